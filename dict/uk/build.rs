@@ -11,19 +11,7 @@ fn main() -> io::Result<()> {
     let mut f = File::create("src/release.rs")
         .expect("Can't create a file");
 
-    let res = Command::new("rustc")
-        .arg("--version")
-        .output()
-        .expect("Can't get rustc version");
-
-    let version = from_utf8(&res.stdout)
-        .expect("Can't convert from utf-8");
-
-    writeln!(f, r"pub static RUSTC_VERSION: &str = {:?};", version)
-        .expect("Can't write to a file");
-
-    writeln!(f, r"pub const DICT_PATH: &str = {:?};", dict_path)
-        .expect("Can't write to a file");
+    writeln!(f, r"pub const DICT_PATH: &str = {:?};", dict_path)?;
 
     Ok(())
 }
@@ -1,11 +1,3 @@
 pub mod release;
 
 pub use release::DICT_PATH;
-
-
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn it_works() {
-    }
-}
@@ -0,0 +1,419 @@
+use std::{collections::BTreeMap, error::Error, fmt};
+
+use crate::{
+    container::stack::StackSource,
+    language::Language,
+    opencorpora::{
+        kind::{case::Case, number::Number, pos::PartOfSpeach},
+        OpencorporaTagReg,
+    },
+    prelude::*,
+    shapes::restore_capitalization,
+};
+
+/// The built-in list of Russian abbreviations, in the format of `Abbreviations::parse`.
+pub const RUSSIAN: &str = "\
+т.е.\tто есть\tCONJ
+т.к.\tтак как\tCONJ
+т.н.\tтак называемый\tADJF,Fixd
+т.д.\tтак далее\tADVB
+т.п.\tтому подобное\tNOUN,inan,neut,Fixd sing
+др.\tдругие\tADJF,Fixd plur,nomn
+и.о.\tисполняющий обязанности\tNOUN,anim,masc
+им.\tимени\tNOUN,inan,neut,Fixd sing,gent
+г.\tгод\tNOUN,inan,masc
+г.\tгород\tNOUN,inan,masc
+гг.\tгоды\tNOUN,inan,masc plur
+в.\tвек\tNOUN,inan,masc
+вв.\tвека\tNOUN,inan,masc plur
+ул.\tулица\tNOUN,inan,femn
+пр.\tпроспект\tNOUN,inan,masc
+пер.\tпереулок\tNOUN,inan,masc
+обл.\tобласть\tNOUN,inan,femn
+д.\tдом\tNOUN,inan,masc
+кв.\tквартира\tNOUN,inan,femn
+стр.\tстраница\tNOUN,inan,femn
+руб.\tрубль\tNOUN,inan,masc
+коп.\tкопейка\tNOUN,inan,femn
+тыс.\tтысяча\tNOUN,inan,femn
+млн\tмиллион\tNOUN,inan,masc
+млрд\tмиллиард\tNOUN,inan,masc
+мин.\tминута\tNOUN,inan,femn
+сек.\tсекунда\tNOUN,inan,femn
+ч.\tчас\tNOUN,inan,masc
+км\tкилометр\tNOUN,inan,masc
+кг\tкилограмм\tNOUN,inan,masc
+проф.\tпрофессор\tNOUN,anim,masc
+акад.\tакадемик\tNOUN,anim,masc
+ООО\tобщество с ограниченной ответственностью\tNOUN,inan,neut
+ОАО\tоткрытое акционерное общество\tNOUN,inan,neut
+ЗАО\tзакрытое акционерное общество\tNOUN,inan,neut
+РФ\tРоссийская Федерация\tNOUN,inan,femn,Geox
+СССР\tСоюз Советских Социалистических Республик\tNOUN,inan,masc,Geox
+";
+
+const CASES: [&str; 6] = ["nomn", "gent", "datv", "accs", "ablt", "loct"];
+
+/// An abbreviation with its expansion.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AbbreviationEntry {
+    /// The abbreviation as it's written: "руб.", "ООО"
+    pub abbr: String,
+    /// The normal form of the expansion: "рубль", "общество с ограниченной ответственностью"
+    pub expansion: String,
+    /// Tags of the forms; the abbreviation itself is the same in all of them
+    pub tags: Vec<OpencorporaTagReg>,
+}
+
+/**
+    A list of abbreviations looked up by `AbbreviationAnalyzer`.
+
+    Each line of the list is an abbreviation, its expansion and the tag of the expansion
+    separated by tabs; empty lines and lines starting with "#" are skipped.
+    An abbreviation may have several expansions, but can't contain whitespace, "," or ";".
+
+    A noun or a numeral without a case in the tag gets a form for every case
+    (and every number, unless the tag has one), so "руб." may be a form of "рубль" in any case.
+    All tags get the "Abbr" grammeme, and acronyms (without dots) get "Fixd" as well:
+
+    ```
+    use rsmorphy::abbreviations::Abbreviations;
+
+    let list = Abbreviations::parse("т.е.\tто есть\tCONJ\nООО\tобщество\tNOUN,inan,neut sing").unwrap();
+    let entries: Vec<_> = list.lookup("ооо").collect();
+    assert_eq!(entries[0].expansion, "общество");
+    assert_eq!(entries[0].tags.len(), 6);
+    assert_eq!(entries[0].tags[2].string, "NOUN,inan,neut,Fixd,Abbr sing,datv");
+    assert_eq!(list.lookup("т.е.").next().unwrap().tags[0].string, "CONJ,Abbr");
+
+    let e = Abbreviations::parse("# comment\n\nт.е.\tто есть").unwrap_err();
+    assert_eq!((e.line, e.reason), (3, "a tab-separated field is missing"));
+    let e = Abbreviations::parse("т. е.\tто есть\tCONJ").unwrap_err();
+    assert_eq!((e.line, e.reason), (1, "the abbreviation contains whitespace"));
+    let e = Abbreviations::parse("т,е\tто есть\tCONJ").unwrap_err();
+    assert_eq!((e.line, e.reason), (1, "the abbreviation contains \",\" or \";\""));
+    ```
+*/
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Abbreviations {
+    entries: Vec<AbbreviationEntry>,
+    /// Ids of the entries by lower case abbreviations
+    index: BTreeMap<String, Vec<usize>>,
+}
+
+impl Abbreviations {
+    /// Parses the list; see the format above.
+    pub fn parse(text: &str) -> Result<Self, ParseAbbreviationsError> {
+        let mut result = Abbreviations::default();
+        for (line_idx, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = |reason| ParseAbbreviationsError {
+                line: line_idx + 1,
+                reason,
+            };
+            let mut fields = line.split('\t').map(str::trim);
+            let (abbr, expansion, tag) = match (fields.next(), fields.next(), fields.next()) {
+                (Some(abbr), Some(expansion), Some(tag)) => (abbr, expansion, tag),
+                _ => return Err(error("a tab-separated field is missing")),
+            };
+            if fields.next().is_some() {
+                return Err(error("too many fields"));
+            }
+            if abbr.is_empty() || expansion.is_empty() {
+                return Err(error("the abbreviation or the expansion is empty"));
+            }
+            if abbr.contains(char::is_whitespace) {
+                return Err(error("the abbreviation contains whitespace"));
+            }
+            // They separate parts of parse ids (see `MorphySerde`)
+            if abbr.contains(&[',', ';'][..]) {
+                return Err(error("the abbreviation contains \",\" or \";\""));
+            }
+            let tag = OpencorporaTagReg::new(tag);
+            if tag.pos.is_none() {
+                return Err(error("the tag has no part of speech"));
+            }
+            result.add(abbr, expansion, &tag);
+        }
+        Ok(result)
+    }
+
+    /// The built-in list of the language.
+    pub fn for_language(language: Language) -> Self {
+        Abbreviations::parse(language.abbreviations()).expect("malformed built-in abbreviations")
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the entries of the abbreviation (in lower case).
+    pub fn lookup<'a>(&'a self, word_lower: &str) -> impl Iterator<Item = &'a AbbreviationEntry> {
+        self.lookup_ids(word_lower)
+            .iter()
+            .map(move |&id| &self.entries[id])
+    }
+
+    pub fn lookup_ids(&self, word_lower: &str) -> &[usize] {
+        self.index.get(word_lower).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn get(&self, id: usize) -> &AbbreviationEntry {
+        &self.entries[id]
+    }
+
+    /// Checks the entry `id` has the form `idx`.
+    pub fn has_form(&self, id: usize, idx: usize) -> bool {
+        match self.entries.get(id) {
+            Some(entry) => idx < entry.tags.len(),
+            None => false,
+        }
+    }
+
+    fn add(&mut self, abbr: &str, expansion: &str, tag: &OpencorporaTagReg) {
+        let (lemma_part, form_part) = match tag.string.find(' ') {
+            Some(pos) => (&tag.string[..pos], Some(&tag.string[pos + 1..])),
+            None => (tag.string.as_str(), None),
+        };
+        let is_fixed = !abbr.contains('.') && !tag.grammemes.set.contains(&Grammeme::new("Fixd"));
+        let lemma_part = match is_fixed {
+            true => format!("{},Fixd,Abbr", lemma_part),
+            false => format!("{},Abbr", lemma_part),
+        };
+        let is_declined = match tag.pos {
+            Some(PartOfSpeach::Noun) | Some(PartOfSpeach::Numr) => tag.case.is_none(),
+            _ => false,
+        };
+        let numbers: &[&str] = match (tag.pos, tag.number) {
+            (Some(PartOfSpeach::Numr), _) => &[""],
+            (_, Some(Number::Sing)) => &["sing,"],
+            (_, Some(Number::Plur)) => &["plur,"],
+            _ => &["sing,", "plur,"],
+        };
+        let tags = match (is_declined, form_part) {
+            (true, _) => numbers
+                .iter()
+                .flat_map(|number| CASES.iter().map(move |case| (number, case)))
+                .map(|(number, case)| {
+                    OpencorporaTagReg::new(format!("{} {}{}", lemma_part, number, case))
+                })
+                .collect(),
+            (false, Some(form_part)) => {
+                vec![OpencorporaTagReg::new(format!(
+                    "{} {}",
+                    lemma_part, form_part
+                ))]
+            }
+            (false, None) => vec![OpencorporaTagReg::new(lemma_part)],
+        };
+
+        self.index
+            .entry(abbr.to_lowercase())
+            .or_default()
+            .push(self.entries.len());
+        self.entries.push(AbbreviationEntry {
+            abbr: abbr.to_owned(),
+            expansion: expansion.to_owned(),
+            tags,
+        });
+    }
+}
+
+/// A line of an abbreviation list is malformed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseAbbreviationsError {
+    /// The number of the line, from 1
+    pub line: usize,
+    pub reason: &'static str,
+}
+
+impl fmt::Display for ParseAbbreviationsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.reason)
+    }
+}
+
+impl Error for ParseAbbreviationsError {}
+
+/// Returns the expansion of the abbreviation in the case and the number of its form:
+/// "руб." in datv plur is "рублям".
+///
+/// Returns `None` if the word is not an abbreviation from `MorphAnalyzer::abbreviations`.
+pub fn expand_lex(morph: &MorphAnalyzer, lex: &Lex) -> Option<String> {
+    let stack = &lex.stack;
+    if stack.particle.is_some() || stack.stack.right.is_some() || stack.stack.left.affix.is_some() {
+        return None;
+    }
+    let abbreviation = match &stack.stack.left.stack {
+        StackSource::Abbreviation(abbreviation) => abbreviation,
+        _ => return None,
+    };
+    let entry = morph.abbreviations.get(abbreviation.id());
+    if entry.tags.len() == 1 {
+        return Some(entry.expansion.clone());
+    }
+
+    let tag = lex.get_tag(morph);
+    let required = GrammemeSet {
+        set: tag
+            .case
+            .map(Case::to_grammeme)
+            .into_iter()
+            .chain(tag.number.map(Number::to_grammeme))
+            .collect(),
+    };
+    let phrase: Vec<Lex> = entry
+        .expansion
+        .split_whitespace()
+        .map(|word| {
+            let parses = morph.parse(word);
+            parses
+                .iter()
+                .find(|parsed| parsed.lex.get_tag(morph).case == Some(Case::Nomn))
+                .or_else(|| parses.first())
+                .map(|parsed| parsed.lex.clone())
+        })
+        .collect::<Option<_>>()?;
+    let words: Vec<String> = match Lex::inflect_phrase(morph, &phrase, &required) {
+        Some(phrase) => phrase
+            .iter()
+            .map(|lex| lex.get_word().into_owned())
+            .collect(),
+        None => return Some(entry.expansion.clone()),
+    };
+    let expansion = entry
+        .expansion
+        .split_whitespace()
+        .zip(words)
+        .map(|(example, word)| restore_capitalization(&word, example))
+        .collect::<Vec<_>>();
+    Some(expansion.join(" "))
+}
+
+/**
+    Replaces abbreviations in the text with their expansions, for text normalization:
+    "ул. Ленина, д. 5" becomes "улица Ленина, дом 5".
+
+    Words are parsed one by one, so the form of an abbreviation is taken from its most probable parse;
+    set `MorphAnalyzer::tag_model` to choose it by the neighbouring words.
+    A capitalized dotted abbreviation has its expansion capitalized: "Ул." is "Улица".
+
+    ```no_run
+    use rsmorphy::{abbreviations::expand, MorphAnalyzer};
+
+    let morph = MorphAnalyzer::from_file(dict_ru::DICT_PATH);
+    assert_eq!(expand(&morph, "т.е. ООО"), "то есть общество с ограниченной ответственностью");
+    ```
+*/
+pub fn expand(morph: &MorphAnalyzer, text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut pos = 0;
+    for token in morph.analyze_text(text) {
+        let (start, end) = token.span();
+        let word = token.surface();
+        let expansion = token
+            .parses
+            .first()
+            .and_then(|parsed| expand_lex(morph, &parsed.lex));
+        if let Some(expansion) = expansion {
+            result.push_str(&text[pos..start]);
+            let is_title = word.contains('.') && word.starts_with(char::is_uppercase);
+            match is_title {
+                true => {
+                    let mut chars = expansion.chars();
+                    result.extend(chars.next().into_iter().flat_map(char::to_uppercase));
+                    result.push_str(chars.as_str());
+                }
+                false => result.push_str(&expansion),
+            }
+            pos = end;
+        }
+    }
+    result.push_str(&text[pos..]);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        container::{abc::*, Lex},
+        opencorpora::{Grammeme, GrammemeSet, LoadError},
+    };
+
+    lazy_static::lazy_static! {
+        static ref RU: MorphAnalyzer = MorphAnalyzer::from_file(dict_ru::DICT_PATH);
+    }
+
+    #[test]
+    fn abbreviations() {
+        let expand = |word: &str, grammemes: &str| -> Vec<String> {
+            RU.parse(word)
+                .iter()
+                .filter(|p| {
+                    let tag = p.lex.get_tag(&RU);
+                    GrammemeSet::new(grammemes)
+                        .set
+                        .is_subset(&tag.grammemes.set)
+                })
+                .filter_map(|p| expand_lex(&RU, &p.lex))
+                .collect()
+        };
+        assert_eq!(expand("руб.", "datv,plur"), ["рублям"]);
+        assert_eq!(expand("г.", "loct,sing"), ["годе", "городе"]);
+        assert_eq!(expand("гг.", "loct,plur"), ["годах"]);
+        assert_eq!(expand("вв.", "gent,plur"), ["веков"]);
+        assert_eq!(expand("т.п.", "accs"), ["тому подобное"]);
+        // "год" and "город" have the same tags
+        let tags: Vec<&str> = RU.tag("г.").iter().map(|tag| tag.string.as_str()).collect();
+        let unique: std::collections::BTreeSet<&str> = tags.iter().cloned().collect();
+        assert_eq!(tags.len(), unique.len());
+        assert_eq!(expand("т.е.", "CONJ"), ["то есть"]);
+        assert_eq!(
+            expand("ООО", "Fixd,ablt,sing"),
+            ["обществом с ограниченной ответственностью"]
+        );
+        assert_eq!(expand("РФ", "gent,sing"), ["Российской Федерации"]);
+
+        let lex = &RU.parse("ул.")[0].lex;
+        assert!(lex
+            .get_tag(&RU)
+            .grammemes
+            .set
+            .contains(&Grammeme::new("Abbr")));
+        assert_eq!(lex.get_normal_form(&RU), "ул.");
+        assert_eq!(lex.get_lexeme(&RU).len(), 12);
+        assert_eq!(Lex::from_id(&RU, lex.encoded()).as_ref(), Ok(lex));
+
+        assert_eq!(
+            super::expand(&RU, "Ул. Ленина, т.е. ООО"),
+            "Улица Ленина, то есть общество с ограниченной ответственностью"
+        );
+
+        let path = std::env::temp_dir().join("rsmorphy-abbreviations.txt");
+        std::fs::write(
+            &path,
+            "ЖКХ\tжилищно-коммунальное хозяйство\tNOUN,inan,neut\n",
+        )
+        .unwrap();
+        let mut morph = RU.clone();
+        morph.load_abbreviations(&path).unwrap();
+        assert_eq!(morph.parse("жкх")[0].lex.get_word(), "ЖКХ");
+        assert!(morph.abbreviations.lookup("т.е.").next().is_none());
+        // The entry of the built-in list is absent in the loaded one
+        assert_eq!(
+            Lex::from_id(&morph, lex.encoded()),
+            Err(DecodeError::DoesntMatch)
+        );
+
+        std::fs::write(&path, "ЖКХ\tхозяйство\n").unwrap();
+        match morph.load_abbreviations(&path) {
+            Err(LoadError::MalformedList { line: 1, .. }) => {}
+            result => panic!("unexpected result: {:?}", result),
+        }
+        std::fs::remove_file(&path).unwrap();
+    }
+}
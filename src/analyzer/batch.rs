@@ -0,0 +1,92 @@
+use std::{collections::HashMap, panic, thread};
+
+use crate::{analyzer::MorphAnalyzer, ParseResult};
+
+impl MorphAnalyzer {
+    /// Analyzes the words and returns a list of `Parsed` for each of them, in the same order.
+    ///
+    /// Every distinct word is parsed once; the work is distributed among
+    /// `batch_threads` threads sharing the analyzer.
+    pub fn parse_batch<S>(&self, words: &[S]) -> Vec<ParseResult>
+    where
+        S: AsRef<str>,
+    {
+        self.map_batch(words, |word| self.parse(word))
+    }
+
+    /// Returns the normal form of the most probable parse for each of the words, in the same order.
+    pub fn lemmatize_batch<S>(&self, words: &[S]) -> Vec<String>
+    where
+        S: AsRef<str>,
+    {
+        self.map_batch(words, |word| self.lemmatize(word).into_owned())
+    }
+
+    fn map_batch<S, T, F>(&self, words: &[S], f: F) -> Vec<T>
+    where
+        S: AsRef<str>,
+        T: Clone + Send,
+        F: Fn(&str) -> T + Sync,
+    {
+        let mut unique: Vec<&str> = Vec::new();
+        let mut index: HashMap<&str, usize> = HashMap::new();
+        let positions: Vec<usize> = words
+            .iter()
+            .map(|word| {
+                let word = word.as_ref();
+                *index.entry(word).or_insert_with(|| {
+                    unique.push(word);
+                    unique.len() - 1
+                })
+            })
+            .collect();
+
+        let threads = self.batch_threads.max(1).min(unique.len());
+        let results: Vec<T> = if threads <= 1 {
+            unique.iter().map(|word| f(word)).collect()
+        } else {
+            let chunk_size = (unique.len() + threads - 1) / threads;
+            let f = &f;
+            thread::scope(|scope| {
+                let handles: Vec<_> = unique
+                    .chunks(chunk_size)
+                    .map(|chunk| {
+                        scope.spawn(move || chunk.iter().map(|word| f(word)).collect::<Vec<T>>())
+                    })
+                    .collect();
+                handles
+                    .into_iter()
+                    .flat_map(|handle| handle.join().unwrap_or_else(|e| panic::resume_unwind(e)))
+                    .collect::<Vec<T>>()
+            })
+        };
+
+        positions.into_iter().map(|i| results[i].clone()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{container::abc::*, MorphAnalyzer};
+
+    lazy_static::lazy_static! {
+        static ref RU: MorphAnalyzer = MorphAnalyzer::from_file(dict_ru::DICT_PATH);
+    }
+
+    #[test]
+    fn parse_batch() {
+        let words = ["стали", "Мама", "стали", "мыла", "раму", "мама"];
+        let morph = MorphAnalyzer::builder()
+            .batch_threads(3)
+            .build(RU.dict.clone());
+        let parsed = morph.parse_batch(&words);
+        assert_eq!(parsed.len(), words.len());
+        for (word, result) in words.iter().zip(&parsed) {
+            assert_eq!(result, &morph.parse(word));
+        }
+        let lemmas = morph.lemmatize_batch(&words);
+        for (result, lemma) in parsed.iter().zip(&lemmas) {
+            assert_eq!(result[0].lex.get_normal_form(&morph), lemma.as_str());
+        }
+    }
+}
@@ -0,0 +1,220 @@
+use std::{collections::BTreeMap, num::NonZeroUsize, path::Path, sync::Arc, thread};
+
+use crate::{
+    abbreviations::Abbreviations,
+    analyzer::{
+        cache::ParseCache,
+        morph::Units,
+        pipeline::{default_pipeline, PipelineStep},
+        units::AnalyzerUnit,
+        MorphAnalyzer,
+    },
+    disambiguator::TagBigramModel,
+    estimator::{Estimator, SingleTagProbabilityEstimator},
+    opencorpora::{Dictionary, LoadError, UserLexicon},
+};
+
+/// Configures and creates `MorphAnalyzer`.
+///
+/// ```no_run
+/// use rsmorphy::analyzer::units::{KnownSuffixAnalyzer, LatinAnalyzer, UnknownPrefixAnalyzer};
+/// use rsmorphy::MorphAnalyzer;
+///
+/// let morph = MorphAnalyzer::builder()
+///     .remove_unit::<LatinAnalyzer>()
+///     .insert_unit_before::<KnownSuffixAnalyzer>(Box::new(UnknownPrefixAnalyzer::default()), false)
+///     .load(dict_ru::DICT_PATH)
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct MorphAnalyzerBuilder {
+    pipeline: Vec<PipelineStep>,
+    batch_threads: usize,
+    cache_capacity: Option<usize>,
+    char_substitutes: Option<BTreeMap<String, String>>,
+    tag_model: Option<Arc<TagBigramModel>>,
+    estimator: Arc<dyn Estimator>,
+}
+
+impl Default for MorphAnalyzerBuilder {
+    fn default() -> Self {
+        let batch_threads = thread::available_parallelism()
+            .map(NonZeroUsize::get)
+            .unwrap_or(1);
+        MorphAnalyzerBuilder {
+            pipeline: default_pipeline(),
+            batch_threads,
+            cache_capacity: None,
+            char_substitutes: None,
+            tag_model: None,
+            estimator: Arc::new(SingleTagProbabilityEstimator {}),
+        }
+    }
+}
+
+impl MorphAnalyzerBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces the whole pipeline with a sequence of units, each with its own "stop if found" flag.
+    pub fn units<I>(mut self, units: I) -> Self
+    where
+        I: IntoIterator<Item = (Box<dyn AnalyzerUnit>, bool)>,
+    {
+        self.pipeline = units
+            .into_iter()
+            .map(|(unit, stop_if_found)| PipelineStep::new(unit, stop_if_found))
+            .collect();
+        self
+    }
+
+    /// Appends a unit to the end of the pipeline.
+    pub fn unit(mut self, unit: Box<dyn AnalyzerUnit>, stop_if_found: bool) -> Self {
+        self.pipeline.push(PipelineStep::new(unit, stop_if_found));
+        self
+    }
+
+    /// Inserts a unit right before the first unit of type `U`,
+    /// or appends it if there is no such unit.
+    pub fn insert_unit_before<U: AnalyzerUnit>(
+        mut self,
+        unit: Box<dyn AnalyzerUnit>,
+        stop_if_found: bool,
+    ) -> Self {
+        let pos = self
+            .pipeline
+            .iter()
+            .position(PipelineStep::is::<U>)
+            .unwrap_or(self.pipeline.len());
+        self.pipeline
+            .insert(pos, PipelineStep::new(unit, stop_if_found));
+        self
+    }
+
+    /// Removes all units of type `U` from the pipeline.
+    pub fn remove_unit<U: AnalyzerUnit>(mut self) -> Self {
+        self.pipeline.retain(|step| !step.is::<U>());
+        self
+    }
+
+    /// Sets the number of threads used by batch methods; the number of CPUs by default.
+    pub fn batch_threads(mut self, batch_threads: usize) -> Self {
+        self.batch_threads = batch_threads;
+        self
+    }
+
+    /// Enables caching of parse results for up to `capacity` recently parsed words.
+    pub fn cache(mut self, capacity: usize) -> Self {
+        self.cache_capacity = Some(capacity);
+        self
+    }
+
+    /// Overrides letters which may be written instead of others (e.g. "е" instead of "ё");
+    /// by default they are taken from the dictionary.
+    pub fn char_substitutes<I, K, V>(mut self, char_substitutes: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let char_substitutes = char_substitutes
+            .into_iter()
+            .map(|(k, v)| (k.into(), v.into()))
+            .collect();
+        self.char_substitutes = Some(char_substitutes);
+        self
+    }
+
+    /// Disables substitutes, so words are looked up exactly as they are written
+    /// (e.g. "е" never matches "ё").
+    pub fn strict_substitutes(mut self) -> Self {
+        self.char_substitutes = Some(BTreeMap::new());
+        self
+    }
+
+    /// Replaces the estimator ranking parses of a single word.
+    pub fn estimator(mut self, estimator: Box<dyn Estimator>) -> Self {
+        self.estimator = Arc::from(estimator);
+        self
+    }
+
+    /// Sets the model used to disambiguate parses by the context (see `MorphAnalyzer::disambiguate`).
+    pub fn tag_model<M>(mut self, tag_model: M) -> Self
+    where
+        M: Into<Arc<TagBigramModel>>,
+    {
+        self.tag_model = Some(tag_model.into());
+        self
+    }
+
+    /// Creates `MorphAnalyzer` with preloaded dict
+    pub fn build<D>(self, dict: D) -> MorphAnalyzer
+    where
+        D: Into<Arc<Dictionary>>,
+    {
+        let dict = dict.into();
+        let char_substitutes = self
+            .char_substitutes
+            .unwrap_or_else(|| dict.char_substitutes.clone());
+        let abbreviations = Arc::new(Abbreviations::for_language(dict.language));
+        MorphAnalyzer {
+            dict,
+            estimator: self.estimator,
+            units: Units::default(),
+            lexicon: Arc::new(UserLexicon::default()),
+            abbreviations,
+            char_substitutes,
+            pipeline: self.pipeline,
+            batch_threads: self.batch_threads,
+            cache: self.cache_capacity.map(ParseCache::new),
+            tag_model: self.tag_model,
+        }
+    }
+
+    /// Loads `Dictionary` from disk and creates `MorphAnalyzer`
+    pub fn load<P>(self, p: P) -> Result<MorphAnalyzer, LoadError>
+    where
+        P: AsRef<Path>,
+    {
+        Ok(self.build(Dictionary::try_from_file(p)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{container::abc::*, opencorpora::LexiconError};
+
+    lazy_static::lazy_static! {
+        static ref RU: MorphAnalyzer = MorphAnalyzer::from_file(dict_ru::DICT_PATH);
+    }
+
+    #[test]
+    fn char_substitutes() {
+        let is_known_yo = |morph: &MorphAnalyzer| {
+            morph
+                .parse("елка")
+                .iter()
+                .any(|p| p.lex.is_known() && p.lex.get_word() == "ёлка")
+        };
+        assert_eq!(RU.char_substitutes.get("е").map(String::as_str), Some("ё"));
+        assert!(is_known_yo(&RU));
+
+        let strict = MorphAnalyzer::builder()
+            .strict_substitutes()
+            .build(RU.dict.clone());
+        assert!(!is_known_yo(&strict));
+        assert!(strict.parse("ёлка").iter().any(|p| p.lex.is_known()));
+
+        // The user lexicon follows the substitutes of the analyzer too
+        let mut strict = strict;
+        assert_eq!(
+            strict.add_word_like("ёжка", "елка"),
+            Err(LexiconError::UnknownExample("елка".into()))
+        );
+        strict.add_word_like("ёжка", "ёлка").unwrap();
+        assert!(strict.parse("ёжкой").iter().any(|p| p.lex.is_known()));
+        assert!(!strict.parse("ежкой").iter().any(|p| p.lex.is_known()));
+    }
+}
@@ -0,0 +1,235 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex, MutexGuard, PoisonError,
+    },
+};
+
+use crate::container::ParseResult;
+
+/// Statistics of `ParseCache`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub len: usize,
+    pub capacity: usize,
+}
+
+/// A thread-safe bounded cache of parse results with the least recently used eviction.
+///
+/// A clone of the cache is empty and has the same capacity.
+pub struct ParseCache {
+    lru: Mutex<Lru>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl ParseCache {
+    pub fn new(capacity: usize) -> Self {
+        ParseCache {
+            lru: Mutex::new(Lru::new(capacity)),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    pub fn get(&self, word: &str) -> Option<ParseResult> {
+        let result = self.lock().get(word);
+        match result {
+            Some(_) => self.hits.fetch_add(1, Ordering::Relaxed),
+            None => self.misses.fetch_add(1, Ordering::Relaxed),
+        };
+        result
+    }
+
+    pub fn insert(&self, word: &str, result: ParseResult) {
+        self.lock().insert(word, result)
+    }
+
+    /// Removes all entries; statistics of hits and misses are kept.
+    pub fn clear(&self) {
+        self.lock().clear()
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        let lru = self.lock();
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            len: lru.map.len(),
+            capacity: lru.capacity,
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Lru> {
+        // The cache is consistent even if a thread panicked while holding the lock
+        self.lru.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Clone for ParseCache {
+    fn clone(&self) -> Self {
+        ParseCache::new(self.lock().capacity)
+    }
+}
+
+impl fmt::Debug for ParseCache {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ParseCache")
+            .field("stats", &self.stats())
+            .finish()
+    }
+}
+
+const NIL: usize = usize::MAX;
+
+struct Entry {
+    word: String,
+    result: ParseResult,
+    prev: usize,
+    next: usize,
+}
+
+/// Entries are kept in a slab and linked into a list from the most to the least recently used.
+struct Lru {
+    capacity: usize,
+    map: HashMap<String, usize>,
+    entries: Vec<Entry>,
+    head: usize,
+    tail: usize,
+}
+
+impl Lru {
+    fn new(capacity: usize) -> Self {
+        Lru {
+            capacity,
+            map: HashMap::new(),
+            entries: Vec::new(),
+            head: NIL,
+            tail: NIL,
+        }
+    }
+
+    fn get(&mut self, word: &str) -> Option<ParseResult> {
+        let idx = *self.map.get(word)?;
+        self.detach(idx);
+        self.attach_front(idx);
+        Some(self.entries[idx].result.clone())
+    }
+
+    fn insert(&mut self, word: &str, result: ParseResult) {
+        if self.capacity == 0 {
+            return;
+        }
+        if let Some(&idx) = self.map.get(word) {
+            self.entries[idx].result = result;
+            self.detach(idx);
+            self.attach_front(idx);
+            return;
+        }
+        let entry = Entry {
+            word: word.to_owned(),
+            result,
+            prev: NIL,
+            next: NIL,
+        };
+        let idx = if self.entries.len() < self.capacity {
+            self.entries.push(entry);
+            self.entries.len() - 1
+        } else {
+            let idx = self.tail;
+            self.detach(idx);
+            self.map.remove(&self.entries[idx].word);
+            self.entries[idx] = entry;
+            idx
+        };
+        self.attach_front(idx);
+        self.map.insert(word.to_owned(), idx);
+    }
+
+    fn clear(&mut self) {
+        self.map.clear();
+        self.entries.clear();
+        self.head = NIL;
+        self.tail = NIL;
+    }
+
+    fn detach(&mut self, idx: usize) {
+        let (prev, next) = (self.entries[idx].prev, self.entries[idx].next);
+        match prev {
+            NIL => self.head = next,
+            prev => self.entries[prev].next = next,
+        }
+        match next {
+            NIL => self.tail = prev,
+            next => self.entries[next].prev = prev,
+        }
+    }
+
+    fn attach_front(&mut self, idx: usize) {
+        self.entries[idx].prev = NIL;
+        self.entries[idx].next = self.head;
+        match self.head {
+            NIL => self.tail = idx,
+            head => self.entries[head].prev = idx,
+        }
+        self.head = idx;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MorphAnalyzer;
+
+    lazy_static::lazy_static! {
+        static ref RU: MorphAnalyzer = MorphAnalyzer::from_file(dict_ru::DICT_PATH);
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let cache = ParseCache::new(2);
+        cache.insert("a", ParseResult::new());
+        cache.insert("b", ParseResult::new());
+        assert!(cache.get("a").is_some());
+        cache.insert("c", ParseResult::new());
+        assert!(cache.get("b").is_none());
+        assert!(cache.get("a").is_some());
+        assert!(cache.get("c").is_some());
+        assert_eq!(
+            cache.stats(),
+            CacheStats {
+                hits: 3,
+                misses: 1,
+                len: 2,
+                capacity: 2,
+            }
+        );
+
+        cache.clear();
+        assert!(cache.get("a").is_none());
+        assert_eq!(cache.stats().len, 0);
+        assert_eq!(
+            cache.clone().stats(),
+            CacheStats {
+                capacity: 2,
+                ..CacheStats::default()
+            }
+        );
+    }
+
+    #[test]
+    fn parse_cache() {
+        let morph = MorphAnalyzer::builder().cache(16).build(RU.dict.clone());
+        let parsed = morph.parse("стали");
+        assert_eq!(morph.parse("стали"), parsed);
+        let stats = morph.cache_stats().unwrap();
+        assert_eq!((stats.hits, stats.misses, stats.len), (1, 1, 1));
+        morph.clear_cache();
+        assert_eq!(morph.cache_stats().unwrap().len, 0);
+        assert_eq!(RU.cache_stats(), None);
+    }
+}
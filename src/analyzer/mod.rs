@@ -1,4 +1,13 @@
+pub mod batch;
+pub mod builder;
+pub mod cache;
 pub mod morph;
+pub mod pipeline;
+pub mod text;
 pub mod units;
 
+pub use self::builder::MorphAnalyzerBuilder;
+pub use self::cache::{CacheStats, ParseCache};
 pub use self::morph::MorphAnalyzer;
+pub use self::pipeline::PipelineStep;
+pub use self::text::AnalyzedToken;
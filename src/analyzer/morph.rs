@@ -1,12 +1,23 @@
-use std::path::Path;
+use std::{borrow::Cow, cmp::Ordering, collections::BTreeMap, fs, path::Path, sync::Arc};
 
 use crate::{
-    analyzer::units::*,
-    container::{ParseResult, SeenSet},
-    estimator::SingleTagProbabilityEstimator,
-    opencorpora::dictionary::Dictionary,
+    abbreviations::Abbreviations,
+    analyzer::{
+        builder::MorphAnalyzerBuilder,
+        cache::{CacheStats, ParseCache},
+        pipeline::PipelineStep,
+        units::*,
+    },
+    container::{abc::*, paradigm::ParadigmId, ParseResult, Parsed, SeenSet},
+    disambiguator::TagBigramModel,
+    estimator::Estimator,
+    language::Language,
+    opencorpora::{
+        dictionary::Dictionary, LexiconError, LoadError, OpencorporaTagReg, UserLexicon,
+    },
 };
 
+/// Instances of the units whose tags are referred by word containers.
 #[derive(Debug, Default, Clone)]
 pub struct Units {
     pub dictionary: DictionaryAnalyzer,
@@ -24,92 +35,265 @@ pub struct Units {
     pub unknown: UnknownAnalyzer,
 }
 
+/// Morphological analyzer.
+///
+/// The analyzer is `Send + Sync`, so one instance may be used from many threads at once.
+/// Cloning is cheap: the dictionary and the user lexicon are shared between clones
+/// until the lexicon of one of them is changed.
 #[derive(Debug, Clone)]
 pub struct MorphAnalyzer {
-    pub dict: Dictionary,
-    pub estimator: SingleTagProbabilityEstimator,
+    pub dict: Arc<Dictionary>,
+    /// Ranks parses of a single word; `SingleTagProbabilityEstimator` by default.
+    pub estimator: Arc<dyn Estimator>,
     pub units: Units,
+    /// Words registered at runtime.
+    pub lexicon: Arc<UserLexicon>,
+    /// Abbreviations with their expansions; the built-in list of the language by default.
+    ///
+    /// Ids of abbreviation parses are only valid for analyzers with the same list.
+    pub abbreviations: Arc<Abbreviations>,
+    /// Letters which may be written instead of others, e.g. "е" instead of "ё".
+    pub char_substitutes: BTreeMap<String, String>,
+    /// Units to look the word in, in order.
+    pub pipeline: Vec<PipelineStep>,
+    /// The number of threads used by `parse_batch` and `lemmatize_batch`.
+    pub batch_threads: usize,
+    /// Results of `parse` for recently seen words; clones of the analyzer start with an empty cache.
+    pub cache: Option<ParseCache>,
+    /// The model used by `disambiguate`; parses are ranked by single words without it.
+    pub tag_model: Option<Arc<TagBigramModel>>,
 }
 
 impl MorphAnalyzer {
     /// Creates `MorphAnalyzer` with preloaded dict
-    pub fn new(dict: Dictionary) -> Self {
-        let estimator = SingleTagProbabilityEstimator {};
-        let units = Units::default();
-        MorphAnalyzer {
-            dict,
-            estimator,
-            units,
-        }
+    ///
+    /// The dict may be shared with other analyzers by passing `Arc<Dictionary>`.
+    pub fn new<D>(dict: D) -> Self
+    where
+        D: Into<Arc<Dictionary>>,
+    {
+        MorphAnalyzer::builder().build(dict)
+    }
+
+    /// Creates a builder to configure the analyzer pipeline.
+    pub fn builder() -> MorphAnalyzerBuilder {
+        MorphAnalyzerBuilder::default()
     }
 
     /// Loads `Dictionary` from disk and creates `MorphAnalyzer`
+    ///
+    /// Panics if the dictionary can't be loaded; see `try_from_file`.
     pub fn from_file<P>(p: P) -> Self
     where
         P: AsRef<Path>,
     {
-        let dict = Dictionary::from_file(p);
-        // TODO consider move into dict?
-        // char_substitutes = dictionary.words.compile_replaces(char_substitutes or {})
-        MorphAnalyzer::new(dict)
+        MorphAnalyzer::new(Dictionary::from_file(p))
     }
 
-    /// Analyze the word and return a list of `Parsed`:
-    pub fn parse(&self, word: &str) -> ParseResult {
-        let word_lower = word.to_lowercase();
+    /// Loads `Dictionary` from disk and creates `MorphAnalyzer`
+    pub fn try_from_file<P>(p: P) -> Result<Self, LoadError>
+    where
+        P: AsRef<Path>,
+    {
+        Ok(MorphAnalyzer::new(Dictionary::try_from_file(p)?))
+    }
 
-        let look_over = || -> ParseResult {
-            let mut result = ParseResult::new();
-            let mut seen = SeenSet::default();
+    /// The language of the dictionary.
+    pub fn language(&self) -> Language {
+        self.dict.language
+    }
 
-            macro_rules! look_in (
-                ($t: ident) => {{
-                    self.units.$t.parse(self, &mut result, word, &word_lower, &mut seen);
-                }};
-                ($t: ident, return) => {{
-                    self.units.$t.parse(self, &mut result, word, &word_lower, &mut seen);
-                    if !result.is_empty() { return result };
-                }}
-            );
+    /// Rescores and reorders parses of the words of a sentence taking their neighbours
+    /// into account (see `TagBigramModel::disambiguate`).
+    ///
+    /// Does nothing if there is no `tag_model`, so the parses stay ranked
+    /// by `SingleTagProbabilityEstimator`.
+    pub fn disambiguate(&self, sentence: &mut [ParseResult]) {
+        if let Some(model) = &self.tag_model {
+            model.disambiguate(self, sentence);
+        }
+    }
 
-            look_in!(dictionary);
-            look_in!(initials, return);
+    /// Registers the word `lemma` inflected by the paradigm `para_id`.
+    pub fn add_word_with_paradigm<Id>(
+        &mut self,
+        lemma: &str,
+        para_id: Id,
+    ) -> Result<(), LexiconError>
+    where
+        Id: Into<ParadigmId>,
+    {
+        let result = Arc::make_mut(&mut self.lexicon).add_with_paradigm(
+            &self.dict,
+            &self.char_substitutes,
+            lemma,
+            para_id,
+        );
+        self.clear_cache();
+        result
+    }
 
-            look_in!(number, return);
+    /// Registers the word `lemma` inflected the same way as the dictionary word `example`.
+    pub fn add_word_like(&mut self, lemma: &str, example: &str) -> Result<(), LexiconError> {
+        let result = Arc::make_mut(&mut self.lexicon).add_like(
+            &self.dict,
+            &self.char_substitutes,
+            lemma,
+            example,
+        );
+        self.clear_cache();
+        result
+    }
 
-            look_in!(punct, return);
+    /// Registers a lexeme given as a list of `(form, tag)` pairs; the first form is the normal one.
+    pub fn add_lexeme<I, W, T>(&mut self, forms: I) -> Result<usize, LexiconError>
+    where
+        I: IntoIterator<Item = (W, T)>,
+        W: AsRef<str>,
+        T: Into<String>,
+    {
+        let result =
+            Arc::make_mut(&mut self.lexicon).add_lexeme(&self.dict, &self.char_substitutes, forms);
+        self.clear_cache();
+        result
+    }
 
-            look_in!(roman);
-            look_in!(latin, return);
+    /// Replaces the list of abbreviations with one loaded from a file (see `Abbreviations::parse`).
+    ///
+    /// To keep the built-in entries, prepend them to the file: `Language::abbreviations`.
+    pub fn load_abbreviations<P>(&mut self, p: P) -> Result<(), LoadError>
+    where
+        P: AsRef<Path>,
+    {
+        let path = p.as_ref();
+        let text = fs::read_to_string(path).map_err(|e| LoadError::from_io(path, e))?;
+        let abbreviations = Abbreviations::parse(&text).map_err(|e| LoadError::MalformedList {
+            file: path.into(),
+            line: e.line,
+            reason: e.reason,
+        })?;
+        self.abbreviations = Arc::new(abbreviations);
+        self.clear_cache();
+        Ok(())
+    }
 
-            look_in!(hsp, return);
+    /// Analyze the word and return a list of `Parsed`:
+    pub fn parse(&self, word: &str) -> ParseResult {
+        match self.cache {
+            None => self.parse_uncached(word),
+            Some(ref cache) => cache.get(word).unwrap_or_else(|| {
+                let result = self.parse_uncached(word);
+                cache.insert(word, result.clone());
+                result
+            }),
+        }
+    }
+
+    /// Removes all cached parse results, if the cache is enabled,
+    /// and the cached lexemes of numerals.
+    pub fn clear_cache(&self) {
+        if let Some(ref cache) = self.cache {
+            cache.clear();
+        }
+        self.units.number.numerals.clear();
+    }
+
+    /// Returns statistics of the parse cache, if it is enabled.
+    pub fn cache_stats(&self) -> Option<CacheStats> {
+        self.cache.as_ref().map(ParseCache::stats)
+    }
 
-            look_in!(ha, return);
+    /// Returns possible tags of the word, the most probable first.
+    ///
+    /// It's faster than `parse` since dictionary words are not wrapped into word containers.
+    pub fn tag(&self, word: &str) -> Vec<&OpencorporaTagReg> {
+        let word_lower = word.to_lowercase();
 
-            look_in!(hword, return);
+        let mut tags = Vec::new();
+        let mut seen = SeenSet::default();
+        for step in &self.pipeline {
+            step.unit.tag(self, &mut tags, word, &word_lower, &mut seen);
+            if step.stop_if_found && !tags.is_empty() {
+                break;
+            }
+        }
 
-            look_in!(kp, return);
+        self.estimator
+            .apply_to_tags(self, word, &word_lower, &mut tags);
+        tags
+    }
 
-            look_in!(up);
-            look_in!(ks, return);
+    /// Returns distinct normal forms of the word, the most probable first.
+    pub fn normal_forms(&self, word: &str) -> Vec<String> {
+        let mut result = self.parse(word);
+        result.sort_by(by_score);
+        let mut forms: Vec<String> = Vec::new();
+        for parsed in &result {
+            let form = parsed.lex.get_normal_form(self);
+            if !forms.iter().any(|f| *f == form) {
+                forms.push(form.into_owned());
+            }
+        }
+        forms
+    }
 
-            look_in!(unknown, return);
+    /// Returns the normal form of the most probable parse of the word.
+    pub fn lemmatize<'w>(&self, word: &'w str) -> Cow<'w, str> {
+        let result = self.parse(word);
+        let best = result
+            .iter()
+            .min_by(|p1, p2| by_score(p1, p2))
+            .map(|parsed| parsed.lex.get_normal_form(self));
+        match best {
+            Some(ref form) if form == word => Cow::Borrowed(word),
+            Some(form) => Cow::Owned(form.into_owned()),
+            None => Cow::Owned(word.to_lowercase()),
+        }
+    }
 
-            unreachable!();
-        };
+    fn parse_uncached(&self, word: &str) -> ParseResult {
+        let word_lower = word.to_lowercase();
+
+        let mut result = ParseResult::new();
+        let mut seen = SeenSet::default();
+        for PipelineStep {
+            unit,
+            stop_if_found,
+        } in &self.pipeline
+        {
+            unit.parse(self, &mut result, word, &word_lower, &mut seen);
+            if *stop_if_found && !result.is_empty() {
+                break;
+            }
+        }
 
-        let mut result = look_over();
         self.estimator
             .apply_to_parses(self, word, &word_lower, &mut result);
         result
     }
 }
 
+/// Orders parses from the most to the least probable.
+pub(crate) fn by_score(p1: &Parsed, p2: &Parsed) -> Ordering {
+    p2.score
+        .value()
+        .partial_cmp(&p1.score.value())
+        .unwrap_or(Ordering::Equal)
+}
+
+#[allow(dead_code)]
+fn assert_send_sync() {
+    fn is_send_sync<T: Send + Sync>() {}
+    is_send_sync::<MorphAnalyzer>();
+}
+
 #[cfg(test)]
 mod tests {
+    use std::{borrow::Cow, sync::Arc};
+
     use env_logger;
 
-    use crate::MorphAnalyzer;
+    use crate::{container::abc::*, MorphAnalyzer};
 
     lazy_static::lazy_static! {
         static ref RU: MorphAnalyzer = MorphAnalyzer::from_file(dict_ru::DICT_PATH);
@@ -121,6 +305,14 @@ mod tests {
         let _ = RU.dict;
     }
 
+    #[test]
+    fn clone_shares_dict() {
+        let morph = RU.clone();
+        assert!(Arc::ptr_eq(&morph.dict, &RU.dict));
+        let other = MorphAnalyzer::new(RU.dict.clone());
+        assert!(Arc::ptr_eq(&other.dict, &RU.dict));
+    }
+
     #[test]
     fn parse() {
         assert_eq!(RU.parse("минимальный").len(), 2);
@@ -149,4 +341,24 @@ mod tests {
         assert_eq!(RU.parse("Р-ка").len(), 1);
         assert_eq!(RU.parse("з-то").len(), 1);
     }
+
+    #[test]
+    fn tag_and_lemmatize() {
+        for word in &["стали", "Мама", "бутявкает", "человека-горы", "г."]
+        {
+            let parsed = RU.parse(word);
+            let mut tags: Vec<_> = parsed.iter().map(|p| &p.lex.get_tag(&RU).string).collect();
+            let mut fast_tags: Vec<_> = RU.tag(word).into_iter().map(|t| &t.string).collect();
+            tags.sort();
+            fast_tags.sort();
+            assert_eq!(tags, fast_tags, "{}", word);
+
+            let forms = RU.normal_forms(word);
+            assert_eq!(forms[0], RU.lemmatize(word));
+            for p in &parsed {
+                assert!(forms.contains(&p.lex.get_normal_form(&RU).into_owned()));
+            }
+        }
+        assert_eq!(RU.lemmatize("мама"), Cow::Borrowed("мама"));
+    }
 }
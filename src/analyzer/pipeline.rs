@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use crate::analyzer::units::*;
+
+/// A unit of the analysis pipeline.
+#[derive(Debug, Clone)]
+pub struct PipelineStep {
+    pub unit: Arc<dyn AnalyzerUnit>,
+    /// Stop the analysis after this unit if something has been found so far.
+    pub stop_if_found: bool,
+}
+
+impl PipelineStep {
+    pub fn new(unit: Box<dyn AnalyzerUnit>, stop_if_found: bool) -> Self {
+        let unit = Arc::from(unit);
+        PipelineStep {
+            unit,
+            stop_if_found,
+        }
+    }
+
+    /// Checks whether the unit of this step is of type `U`.
+    ///
+    /// ```
+    /// use rsmorphy::analyzer::{pipeline::default_pipeline, units::*};
+    ///
+    /// let pipeline = default_pipeline();
+    /// assert!(pipeline[0].is::<DictionaryAnalyzer>());
+    /// assert!(!pipeline[0].is::<UnknownAnalyzer>());
+    /// ```
+    pub fn is<U: AnalyzerUnit>(&self) -> bool {
+        // Deref the `Arc` so that the unit itself is cast, not the pointer
+        (*self.unit).as_any().is::<U>()
+    }
+}
+
+/// Returns the default sequence of units, the same as in PyMorphy2
+/// plus the user lexicon and the abbreviations lookup.
+pub fn default_pipeline() -> Vec<PipelineStep> {
+    fn step<U: AnalyzerUnit + Default + 'static>(stop_if_found: bool) -> PipelineStep {
+        PipelineStep::new(Box::new(U::default()), stop_if_found)
+    }
+
+    vec![
+        step::<DictionaryAnalyzer>(false),
+        step::<UserLexiconAnalyzer>(false),
+        step::<AbbreviationAnalyzer>(false),
+        step::<InitialsAnalyzer>(true),
+        step::<NumberAnalyzer>(true),
+        step::<PunctuationAnalyzer>(true),
+        step::<RomanAnalyzer>(false),
+        step::<LatinAnalyzer>(true),
+        step::<HyphenSeparatedParticleAnalyzer>(true),
+        step::<HyphenAdverbAnalyzer>(true),
+        step::<HyphenatedWordsAnalyzer>(true),
+        step::<KnownPrefixAnalyzer>(true),
+        step::<UnknownPrefixAnalyzer>(false),
+        step::<KnownSuffixAnalyzer>(true),
+        step::<UnknownAnalyzer>(true),
+    ]
+}
@@ -0,0 +1,88 @@
+use crate::{
+    analyzer::MorphAnalyzer,
+    tokenize::{tokenize_with_abbreviations, Token},
+    ParseResult,
+};
+
+/// A token of a text with its parses.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnalyzedToken<'t> {
+    /// The token as it is written in the text, with its offsets
+    pub token: Token<'t>,
+    pub parses: ParseResult,
+}
+
+impl<'t> AnalyzedToken<'t> {
+    /// The original-case text of the token.
+    pub fn surface(&self) -> &'t str {
+        self.token.text
+    }
+
+    /// Byte range of the token in the text.
+    pub fn span(&self) -> (usize, usize) {
+        (self.token.start, self.token.end)
+    }
+
+    /// Char range of the token in the text.
+    pub fn char_span(&self) -> (usize, usize) {
+        (self.token.char_start, self.token.char_end)
+    }
+}
+
+impl MorphAnalyzer {
+    /// Splits the text into tokens (see `tokenize_with_abbreviations`)
+    /// and analyzes each of them.
+    ///
+    /// Parses are disambiguated by the context if the analyzer has a `tag_model`.
+    pub fn analyze_text<'t>(&self, text: &'t str) -> Vec<AnalyzedToken<'t>> {
+        let tokens: Vec<Token<'t>> =
+            tokenize_with_abbreviations(text, &self.abbreviations).collect();
+        let mut parses: Vec<ParseResult> =
+            tokens.iter().map(|token| self.parse(token.text)).collect();
+        self.disambiguate(&mut parses);
+        tokens
+            .into_iter()
+            .zip(parses)
+            .map(|(token, parses)| AnalyzedToken { token, parses })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{container::abc::*, opencorpora::Grammeme};
+
+    lazy_static::lazy_static! {
+        static ref RU: MorphAnalyzer = MorphAnalyzer::from_file(dict_ru::DICT_PATH);
+    }
+
+    #[test]
+    fn analyze_text() {
+        let text = "Мама мыла раму по-русски.";
+        let tokens = RU.analyze_text(text);
+        let surfaces: Vec<_> = tokens.iter().map(|t| t.surface()).collect();
+        assert_eq!(surfaces, vec!["Мама", "мыла", "раму", "по-русски", "."]);
+        for token in &tokens {
+            let (start, end) = token.span();
+            assert_eq!(&text[start..end], token.surface());
+            assert_eq!(token.parses, RU.parse(token.surface()));
+        }
+        assert_eq!(tokens[3].char_span(), (15, 24));
+
+        // Initials and known abbreviations are analyzed with their dots
+        let tokens = RU.analyze_text("А. С. Пушкин, г. Москва");
+        let surfaces: Vec<_> = tokens.iter().map(|t| t.surface()).collect();
+        assert_eq!(surfaces, vec!["А.", "С.", "Пушкин", ",", "г.", "Москва"]);
+        let has_grammeme = |token: &AnalyzedToken, grammeme: &str| {
+            let grammeme = Grammeme::new(grammeme);
+            token
+                .parses
+                .iter()
+                .any(|p| p.lex.get_tag(&RU).grammemes.set.contains(&grammeme))
+        };
+        assert!(has_grammeme(&tokens[0], "Init"));
+        assert!(has_grammeme(&tokens[1], "Init"));
+        assert!(has_grammeme(&tokens[4], "Abbr"));
+    }
+}
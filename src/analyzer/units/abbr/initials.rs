@@ -1,4 +1,4 @@
-use std::{borrow::Cow, collections::BTreeSet, iter::FromIterator};
+use std::borrow::Cow;
 
 use crate::{
     analyzer::{units::abc::AnalyzerUnit, MorphAnalyzer},
@@ -8,14 +8,6 @@ use crate::{
     opencorpora::OpencorporaTagReg,
 };
 
-lazy_static::lazy_static! {
-    #[derive(Debug)]
-    pub static ref LETTERS: BTreeSet<&'static str> = {
-        let set = "АБВГДЕЁЖЗИЙКЛМНОПРСТУФХЦЧШЩЭЮЯ".split("").filter(|v| !v.is_empty());
-        BTreeSet::from_iter(set)
-    };
-}
-
 const SCORE: Score = Score::Fake(0.1);
 
 #[derive(Debug, Clone)]
@@ -63,13 +55,8 @@ impl AnalyzerUnit for InitialsAnalyzer {
     ) {
         log::trace!("AbbreviatedFirstNameAnalyzer::parse()");
         log::trace!(r#" word: "{}", word_lower: "{}" "#, word, word_lower);
-        log::trace!(
-            r#" LETTERS: "{:?}" "#,
-            LETTERS.iter().cloned().collect::<Vec<&str>>().join(", ")
-        );
-        log::trace!(r#" LETTERS contains word: "{}" "#, LETTERS.contains(word));
-
-        if let Some(&letter) = LETTERS.get(word) {
+        if let Some(letter) = initial_letter(morph, word) {
+            log::trace!(r#" letter: "{}" "#, letter);
             for (tag_idx, &(_, kind)) in self.tags.iter().enumerate() {
                 let tag_idx = tag_idx as u8;
                 let letter = Cow::from(letter);
@@ -84,3 +71,17 @@ impl AnalyzerUnit for InitialsAnalyzer {
         }
     }
 }
+
+/// Returns the word if it's an upper case letter which may be an initial,
+/// possibly with a dot ("А.", see `tokenize`).
+fn initial_letter(morph: &MorphAnalyzer, word: &str) -> Option<&'static str> {
+    let letters = morph.dict.language.initials_letters();
+    let word = word.strip_suffix('.').unwrap_or(word);
+    let mut chars = word.chars();
+    match (chars.next(), chars.next()) {
+        (Some(ch), None) => letters
+            .find(ch)
+            .map(|pos| &letters[pos..pos + ch.len_utf8()]),
+        _ => None,
+    }
+}
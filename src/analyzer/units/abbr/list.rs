@@ -0,0 +1,60 @@
+use crate::{
+    analyzer::{units::abc::AnalyzerUnit, MorphAnalyzer},
+    container::{stack::StackSource, Abbreviation, Lex, ParseResult, Parsed, Score, SeenSet},
+    opencorpora::OpencorporaTagReg,
+};
+
+const SCORE: Score = Score::Real(1.0);
+
+/// Looks the word up in the list of abbreviations (see `MorphAnalyzer::abbreviations`).
+///
+/// Example: "руб." -> NOUN,inan,masc,Abbr sing,nomn (and other cases); "ООО" -> NOUN,inan,neut,Fixd,Abbr ...
+#[derive(Default, Debug, Clone, Copy)]
+pub struct AbbreviationAnalyzer {}
+
+impl AnalyzerUnit for AbbreviationAnalyzer {
+    fn parse(
+        &self,
+        morph: &MorphAnalyzer,
+        result: &mut ParseResult,
+        word: &str,
+        word_lower: &str,
+        _seen_parses: &mut SeenSet,
+    ) {
+        log::trace!("AbbreviationAnalyzer::parse()");
+        log::trace!(r#" word = "{}", word_lower = "{}" "#, word, word_lower);
+
+        for &id in morph.abbreviations.lookup_ids(word_lower) {
+            let entry = morph.abbreviations.get(id);
+            for idx in 0..entry.tags.len() {
+                let container = Abbreviation::new(entry.abbr.as_str(), id, idx);
+                let lex = Lex::from_stack(morph, StackSource::from(container));
+                result.push(Parsed::new(lex, SCORE));
+            }
+        }
+    }
+
+    fn tag<'m>(
+        &self,
+        morph: &'m MorphAnalyzer,
+        tags: &mut Vec<&'m OpencorporaTagReg>,
+        word: &str,
+        word_lower: &str,
+        _seen_parses: &mut SeenSet,
+    ) {
+        log::trace!("AbbreviationAnalyzer::tag()");
+        log::trace!(r#" word = "{}", word_lower = "{}" "#, word, word_lower);
+
+        // Expansions may share tags: "г." is "год" or "город"
+        let start = tags.len();
+        for tag in morph
+            .abbreviations
+            .lookup(word_lower)
+            .flat_map(|entry| entry.tags.iter())
+        {
+            if !tags[start..].iter().any(|seen| seen.string == tag.string) {
+                tags.push(tag);
+            }
+        }
+    }
+}
@@ -1 +1,2 @@
 pub mod initials;
+pub mod list;
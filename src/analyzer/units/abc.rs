@@ -1,9 +1,23 @@
+use std::{any::Any, fmt};
+
 use crate::{
     analyzer::MorphAnalyzer,
-    container::{ParseResult, SeenSet},
+    container::{abc::*, ParseResult, SeenSet},
+    opencorpora::OpencorporaTagReg,
 };
 
-pub trait AnalyzerUnit {
+/// Gives access to the concrete type of a unit, used to find it in a pipeline.
+pub trait AsAny: Any {
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<T: Any> AsAny for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+pub trait AnalyzerUnit: AsAny + fmt::Debug + Send + Sync {
     fn parse(
         &self,
         morph: &MorphAnalyzer,
@@ -12,4 +26,21 @@ pub trait AnalyzerUnit {
         word_lower: &str,
         seen_parses: &mut SeenSet,
     );
+
+    /// Collects tags of the word.
+    ///
+    /// The default implementation takes them from the result of `parse`;
+    /// units able to find tags without building word containers override it.
+    fn tag<'m>(
+        &self,
+        morph: &'m MorphAnalyzer,
+        tags: &mut Vec<&'m OpencorporaTagReg>,
+        word: &str,
+        word_lower: &str,
+        seen_parses: &mut SeenSet,
+    ) {
+        let mut result = ParseResult::new();
+        self.parse(morph, &mut result, word, word_lower, seen_parses);
+        tags.extend(result.iter().map(|parsed| parsed.lex.get_tag(morph)));
+    }
 }
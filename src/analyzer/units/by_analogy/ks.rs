@@ -70,7 +70,7 @@ impl AnalyzerUnit for KnownSuffixAnalyzer {
                 let (word_start, word_end) = (&word_lower[..pos], &word_lower[pos..]);
                 log::trace!("word_start: {}, word_end: {}", word_start, word_end);
 
-                let para_data = suffixes_dawg.similar_items(word_end, &morph.dict.char_substitutes);
+                let para_data = suffixes_dawg.similar_items(word_end, &morph.char_substitutes);
                 for (fixed_suffix, parses) in para_data {
                     let fixed_word: Cow<str> = if fixed_suffix == word_end {
                         Cow::from(word_lower)
@@ -3,18 +3,6 @@ use crate::{
     container::{stack::StackParticle, HyphenSeparatedParticle, Lex, ParseResult, Parsed, SeenSet},
 };
 
-// TODO move into `Dictionary`
-pub static PARTICLES_AFTER_HYPHEN: [&'static str; 8] = [
-    "-то",
-    "-ка",
-    "-таки",
-    "-де",
-    "-тко",
-    "-тка",
-    "-с",
-    "-ста",
-];
-
 pub const SCORE_DECAY: f64 = 0.9;
 
 /// Parse the word by analyzing it without
@@ -22,6 +10,8 @@ pub const SCORE_DECAY: f64 = 0.9;
 ///
 /// Example: смотри-ка -> смотри + "-ка".
 ///
+/// Particles are defined by the language of the dictionary.
+///
 /// .. note::
 ///
 /// This analyzer doesn't remove particles from the result
@@ -43,7 +33,7 @@ impl AnalyzerUnit for HyphenSeparatedParticleAnalyzer {
         log::trace!("HyphenSeparatedParticleAnalyzer::parse()");
         log::trace!(r#" word = "{}", word_lower = "{}" "#, word, word_lower);
 
-        for &particle in &PARTICLES_AFTER_HYPHEN {
+        for &particle in morph.dict.language.particles_after_hyphen() {
             if word_lower.len() <= particle.len() || !word_lower.ends_with(particle) {
                 continue;
             };
@@ -1,14 +1,11 @@
 use crate::{
     analyzer::{units::abc::*, MorphAnalyzer},
     container::{
-        ParseResult,
-        SeenSet,
-        //        Dictionary,
-        //        HyphenSeparatedParticle,
-        //        Lex,
-        //        Score,
-        //        stack::{Stack, StackAffix, StackHyphenated},
+        abc::*,
+        stack::{hword::similarity_features, StackHyphenated},
+        Lex, ParseResult, Parsed, SeenSet,
     },
+    util::add_parsed_if_not_seen,
 };
 
 /// Parse the word by parsing its hyphen-separated parts.
@@ -18,55 +15,141 @@ use crate::{
 /// * интернет-магазин -> "интернет-" + магазин
 /// * человек-гора -> человек + гора
 
-#[derive(Default, Debug, Clone, Copy)]
-pub struct HyphenatedWordsAnalyzer {}
+#[derive(Debug, Clone, Copy)]
+pub struct HyphenatedWordsAnalyzer {
+    pub estimate_decay: f64,
+}
+
+impl Default for HyphenatedWordsAnalyzer {
+    fn default() -> Self {
+        HyphenatedWordsAnalyzer {
+            estimate_decay: 0.75,
+        }
+    }
+}
 
 impl AnalyzerUnit for HyphenatedWordsAnalyzer {
     fn parse(
         &self,
-        _morph: &MorphAnalyzer,
-        _result: &mut ParseResult,
+        morph: &MorphAnalyzer,
+        result: &mut ParseResult,
         word: &str,
         word_lower: &str,
-        _seen_parses: &mut SeenSet,
+        seen_parses: &mut SeenSet,
     ) {
         log::trace!("HyphenatedWordsAnalyzer::parse()");
         log::trace!(r#" word = "{}", word_lower = "{}" "#, word, word_lower);
 
-        // FIXME unimplemented
-        return;
+        let (left, right) = match self.split(morph, word_lower) {
+            Some(v) => v,
+            None => return,
+        };
+        log::trace!(r#" left = "{}", right = "{}" "#, left, right);
+
+        let left_parses = morph.parse(left);
+        let right_parses = morph.parse(right);
+
+        self.parse_as_variable_both(morph, result, &left_parses, &right_parses, seen_parses);
+        self.parse_as_fixed_left(morph, result, left, &right_parses, seen_parses);
+    }
+}
+
+impl HyphenatedWordsAnalyzer {
+    /// Splits the word into two parts, if it looks like a hyphenated one.
+    fn split<'w>(&self, morph: &MorphAnalyzer, word: &'w str) -> Option<(&'w str, &'w str)> {
+        let pos = word.find('-')?;
+        let (left, right) = (&word[..pos], &word[pos + 1..]);
+        // Words like "-слово", "слово-" or "тёмно-красно-синий" are not supported
+        if left.is_empty() || right.is_empty() || right.contains('-') {
+            return None;
+        }
+        // Known prefixes are handled by `KnownPrefixAnalyzer`
+        if morph.dict.prediction_prefixes.contains(left) {
+            return None;
+        }
+        Some((left, right))
+    }
 
-        //        let splitted: Vec<&str> = word_lower.split("-").collect();
-        //        if splitted.len() > 2 {
-        //            return
-        //        }
-        //        let (left, right) = (morph.parse(splitted[0]), morph.parse(splitted[1]));
+    /// Both parts are inflected: человек-гора -> человека-горы
+    fn parse_as_variable_both(
+        &self,
+        morph: &MorphAnalyzer,
+        result: &mut ParseResult,
+        left_parses: &[Parsed],
+        right_parses: &[Parsed],
+        seen_parses: &mut SeenSet,
+    ) {
+        for left_parsed in left_parses {
+            let left_tag = left_parsed.lex.get_tag(morph);
+            if left_tag.pos.is_none() || !is_simple(&left_parsed.lex) {
+                continue;
+            }
+            let left_features = similarity_features(left_tag);
+            for right_parsed in right_parses {
+                if !is_simple(&right_parsed.lex) {
+                    continue;
+                }
+                if left_features != similarity_features(right_parsed.lex.get_tag(morph)) {
+                    continue;
+                }
+                let container = StackHyphenated::new(
+                    left_parsed.lex.stack.stack.left.clone(),
+                    right_parsed.lex.stack.stack.left.clone(),
+                );
+                let lex = Lex::from_stack(morph, container);
+                let score = left_parsed.score * self.estimate_decay;
+                add_parsed_if_not_seen(morph, result, seen_parses, Parsed::new(lex, score));
+            }
+        }
+    }
+
+    /// Only the right part is inflected: интернет-магазин -> интернет-магазина
+    fn parse_as_fixed_left(
+        &self,
+        morph: &MorphAnalyzer,
+        result: &mut ParseResult,
+        left: &str,
+        right_parses: &[Parsed],
+        seen_parses: &mut SeenSet,
+    ) {
+        for right_parsed in right_parses {
+            if right_parsed.lex.get_tag(morph).pos.is_none() || !is_simple(&right_parsed.lex) {
+                continue;
+            }
+            let container =
+                StackHyphenated::with_fixed_left(left, right_parsed.lex.stack.stack.left.clone());
+            let lex = Lex::from_stack(morph, container);
+            let score = right_parsed.score * self.estimate_decay;
+            add_parsed_if_not_seen(morph, result, seen_parses, Parsed::new(lex, score));
+        }
+    }
+}
+
+/// Checks the word is neither hyphenated nor has a particle.
+fn is_simple(lex: &Lex) -> bool {
+    lex.stack.particle.is_none() && lex.stack.stack.right.is_none()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{container::abc::*, opencorpora::GrammemeSet, MorphAnalyzer};
+
+    lazy_static::lazy_static! {
+        static ref RU: MorphAnalyzer = MorphAnalyzer::from_file(dict_ru::DICT_PATH);
+    }
 
-        //        for particle in PARTICLES_AFTER_HYPHEN.iter() {
-        //            if word_lower.len() <= particle.len() || !word_lower.ends_with(particle) {
-        //                continue
-        //            };
-        //            trace!(r#" particle: "{}" "#, particle);
-        //            let unsuffixed_word = &word_lower[.. word_lower.len() - particle.len()];
-        //            trace!(r#" unsuffixed_word: "{}" "#, unsuffixed_word);
-        //
-        //            'subparse: for parsed in morph.parse(unsuffixed_word) {
-        //                trace!(r#" parsed: {:?} "#, parsed);
-        //                let stack: StackHyphenated = match parsed.lex.stack {
-        //                    // If a word ends with with one of the particles, it can't ends with an another.
-        //                    Stack::HSP(_)               => continue 'subparse,
-        //                    Stack::Hyphenated(ref v)    => v.clone(),
-        //                    Stack::Affix(ref v)         => v.clone().into(),
-        //                    Stack::Source(ref v)        => v.clone().into(),
-        //                };
-        //                let container = StackParticle::Particle(stack, HyphenSeparatedParticle {
-        //                    particle: particle.to_string(),
-        //                });
-        //                result.push(Parsed {
-        //                    lex: Lex::from_stack(morph, container),
-        //                    score: Score::Real(1.0),
-        //                });
-        //            }
-        //        }
+    #[test]
+    fn parse_hyphenated() {
+        let inflect = |word: &str, grammemes: &str| -> Vec<String> {
+            RU.parse(word)
+                .into_iter()
+                .filter_map(|p| p.lex.inflect(&RU, &GrammemeSet::new(grammemes)))
+                .map(|lex| lex.get_word().into_owned())
+                .collect()
+        };
+        assert!(inflect("человек-гора", "gent").contains(&"человека-горы".to_string()));
+        assert!(
+            inflect("интернет-магазин", "plur,ablt").contains(&"интернет-магазинами".to_string())
+        );
     }
 }
@@ -1,9 +1,12 @@
 use crate::{
     analyzer::{units::abc::AnalyzerUnit, MorphAnalyzer},
     container::{
-        stack::StackSource, Dictionary, Lex, ParseResult, Parsed, Score, SeenSet, WordStruct,
+        paradigm::{ParadigmId, ParadigmIndex},
+        stack::StackSource,
+        Dictionary, Lex, ParseResult, Parsed, Score, SeenSet, WordStruct,
     },
     dawg::HH,
+    opencorpora::OpencorporaTagReg,
 };
 
 const DICT_SCORE: Score = Score::Real(1.0);
@@ -26,7 +29,7 @@ impl AnalyzerUnit for DictionaryAnalyzer {
         let para_data = morph
             .dict
             .words
-            .similar_items(word_lower, &morph.dict.char_substitutes);
+            .similar_items(word_lower, &morph.char_substitutes);
         log::trace!(r#" para_data="{:?}" "#, para_data);
 
         // `fixed_word` is a word with proper substitute (e.g. ё) letters
@@ -39,4 +42,30 @@ impl AnalyzerUnit for DictionaryAnalyzer {
             }
         }
     }
+
+    fn tag<'m>(
+        &self,
+        morph: &'m MorphAnalyzer,
+        tags: &mut Vec<&'m OpencorporaTagReg>,
+        word: &str,
+        word_lower: &str,
+        _seen_parses: &mut SeenSet,
+    ) {
+        log::trace!("DictionaryAnalyzer::tag()");
+        log::trace!(r#" word = "{}", word_lower = "{}" "#, word, word_lower);
+
+        let para_data = morph
+            .dict
+            .words
+            .similar_items(word_lower, &morph.char_substitutes);
+        for (_, parses) in para_data {
+            for HH(para_id, idx) in parses {
+                tags.push(
+                    morph
+                        .dict
+                        .get_tag(ParadigmId::new(para_id), ParadigmIndex::new(idx)),
+                );
+            }
+        }
+    }
 }
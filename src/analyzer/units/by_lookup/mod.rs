@@ -1 +1,3 @@
 pub mod dict;
+pub mod translit;
+pub mod user;
@@ -0,0 +1,236 @@
+use crate::{
+    analyzer::{units::abc::AnalyzerUnit, MorphAnalyzer},
+    container::{
+        abc::*, stack::StackSource, Dictionary, Lex, ParseResult, Parsed, SeenSet, Transliterated,
+        WordStruct,
+    },
+    dawg::HH,
+    language::Language,
+};
+
+/// The maximum number of dictionary words found for a word.
+const MAX_CANDIDATES: usize = 32;
+
+/// Latin letters and their combinations with the Cyrillic letters they may stand for,
+/// after GOST 7.79-2000 (system B), ICAO Doc 9303 (passports), BGN/PCGN
+/// and the informal "translit" of chats and search queries.
+const LETTERS: &[(&str, &[&str])] = &[
+    ("shch", &["щ"]),
+    ("sch", &["щ"]),
+    ("shh", &["щ"]),
+    ("zh", &["ж"]),
+    ("kh", &["х"]),
+    ("ts", &["ц"]),
+    ("tc", &["ц"]),
+    ("cz", &["ц"]),
+    ("ch", &["ч"]),
+    ("sh", &["ш"]),
+    ("yo", &["ё"]),
+    ("jo", &["ё"]),
+    ("yu", &["ю"]),
+    ("ju", &["ю"]),
+    ("iu", &["ю"]),
+    ("ya", &["я"]),
+    ("ja", &["я"]),
+    ("ia", &["я"]),
+    ("ye", &["е", "ье", "ъе"]),
+    ("je", &["е"]),
+    ("e'", &["э"]),
+    ("y'", &["ы"]),
+    ("''", &["ъ"]),
+    ("a", &["а"]),
+    ("b", &["б"]),
+    ("c", &["ц", "к"]),
+    ("d", &["д"]),
+    ("e", &["е", "ё", "э"]),
+    ("f", &["ф"]),
+    ("g", &["г"]),
+    ("h", &["х"]),
+    ("i", &["и", "й", "ы"]),
+    ("j", &["й", "ж"]),
+    ("k", &["к"]),
+    ("l", &["л"]),
+    ("m", &["м"]),
+    ("n", &["н"]),
+    ("o", &["о"]),
+    ("p", &["п"]),
+    ("q", &["к"]),
+    ("r", &["р"]),
+    ("s", &["с"]),
+    ("t", &["т"]),
+    ("u", &["у"]),
+    ("v", &["в"]),
+    ("w", &["в"]),
+    ("x", &["х", "кс"]),
+    ("y", &["ы", "й", "и"]),
+    ("z", &["з"]),
+    ("'", &["ь", "ъ"]),
+    ("`", &["ъ", "ь"]),
+];
+
+/// Consonants which may be followed by a soft sign omitted in the Latin spelling ("olga").
+const SOFTENED: &str = "бвгдзклмнпрстфхжшчщ";
+
+/**
+    Looks up Russian words typed in Latin letters: "privet" -> "привет", "zhurnal" -> "журнал".
+
+    The word is transliterated into Cyrillic spellings by several common schemes at once,
+    the spellings present in the dictionary are parsed as `Transliterated` dictionary words
+    with a decayed score, and the original spelling is kept as `Transliterated::surface`.
+
+    The unit is not in the default pipeline; insert it before `LatinAnalyzer`
+    to prefer transliterations over the LATN tag:
+
+    ```no_run
+    use rsmorphy::analyzer::units::{LatinAnalyzer, TranslitAnalyzer};
+    use rsmorphy::MorphAnalyzer;
+
+    let morph = MorphAnalyzer::builder()
+        .insert_unit_before::<LatinAnalyzer>(Box::new(TranslitAnalyzer::default()), true)
+        .load(dict_ru::DICT_PATH)
+        .unwrap();
+    assert_eq!(morph.lemmatize("moskvy"), "москва");
+    ```
+
+    Only the Russian schemes are supported, so nothing is found with other dictionaries.
+*/
+#[derive(Default, Debug, Clone, Copy)]
+pub struct TranslitAnalyzer {}
+
+impl AnalyzerUnit for TranslitAnalyzer {
+    fn parse(
+        &self,
+        morph: &MorphAnalyzer,
+        result: &mut ParseResult,
+        word: &str,
+        word_lower: &str,
+        _seen_parses: &mut SeenSet,
+    ) {
+        log::trace!("TranslitAnalyzer::parse()");
+        log::trace!(r#" word = "{}", word_lower = "{}" "#, word, word_lower);
+
+        let is_translit = word_lower.bytes().any(|b| b.is_ascii_lowercase())
+            && word_lower
+                .bytes()
+                .all(|b| b.is_ascii_lowercase() || b == b'\'' || b == b'`');
+        if morph.language() != Language::Russian || !is_translit {
+            return;
+        }
+
+        let mut para_data = Vec::new();
+        spellings(morph, word_lower, &mut String::new(), &mut para_data);
+        log::trace!(r#" para_data = {:?} "#, para_data);
+
+        // Spellings with substitutes (e.g. "е" and "ё") may lead to the same word
+        let mut seen: Vec<(&str, HH)> = Vec::new();
+        for (fixed_word, parses) in &para_data {
+            for &hh in parses {
+                if seen.contains(&(fixed_word.as_str(), hh)) {
+                    continue;
+                }
+                seen.push((fixed_word, hh));
+                let HH(para_id, idx) = hh;
+                let word_lower = WordStruct::known(fixed_word.as_str());
+                let container =
+                    Transliterated::new(word, Dictionary::new(word_lower, para_id, idx));
+                let score = container.score();
+                let lex = Lex::from_stack(morph, StackSource::from(container));
+                result.push(Parsed::new(lex, score));
+            }
+        }
+    }
+}
+
+/// Collects dictionary words spelled as `prefix` followed by a Cyrillic spelling
+/// of the rest of the word, skipping spellings no dictionary word starts with.
+fn spellings(
+    morph: &MorphAnalyzer,
+    rest: &str,
+    prefix: &mut String,
+    result: &mut Vec<(String, Vec<HH>)>,
+) {
+    if result.len() >= MAX_CANDIDATES || !morph.dict.words.has_keys_with_prefix(prefix) {
+        return;
+    }
+    if rest.is_empty() {
+        let para_data = morph
+            .dict
+            .words
+            .similar_items(prefix, &morph.char_substitutes);
+        result.extend(para_data);
+        return;
+    }
+    for &(latin, options) in LETTERS {
+        if !rest.starts_with(latin) {
+            continue;
+        }
+        let rest = &rest[latin.len()..];
+        for option in options {
+            let len = prefix.len();
+            prefix.push_str(option);
+            spellings(morph, rest, prefix, result);
+            if option.ends_with(|ch| SOFTENED.contains(ch)) {
+                prefix.push('ь');
+                spellings(morph, rest, prefix, result);
+            }
+            prefix.truncate(len);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{analyzer::units::LatinAnalyzer, container::Lex, opencorpora::GrammemeSet};
+
+    lazy_static::lazy_static! {
+        static ref RU: MorphAnalyzer = MorphAnalyzer::from_file(dict_ru::DICT_PATH);
+    }
+
+    #[test]
+    fn parse_translit() {
+        let morph = MorphAnalyzer::builder()
+            .insert_unit_before::<LatinAnalyzer>(Box::new(TranslitAnalyzer::default()), true)
+            .build(RU.dict.clone());
+        for &(latin, lemma) in &[
+            ("privet", "привет"),
+            ("Moskva", "москва"),
+            ("zhurnal", "журнал"),
+            ("shchuka", "щука"),
+            ("schast'e", "счастье"),
+            ("olga", "ольга"),
+            ("yolka", "ёлка"),
+        ] {
+            assert!(
+                morph.normal_forms(latin).iter().any(|form| form == lemma),
+                "{}",
+                latin
+            );
+        }
+
+        let parsed = morph.parse("Moskvy");
+        let lex = &parsed[0].lex;
+        assert_eq!(lex.get_word(), "москвы");
+        let source = &lex.stack.stack.left.stack;
+        assert_eq!(source.as_transliterated().unwrap().surface(), "Moskvy");
+        assert!(parsed.iter().all(|p| p.score.value() < 1.0));
+        assert_eq!(Lex::from_id(&morph, lex.encoded()).as_ref(), Ok(lex));
+
+        // Forms of the lexeme are transliterated words as well
+        let is_translit = |lex: &Lex| {
+            let source = &lex.stack.stack.left.stack;
+            source.as_transliterated().map(|t| t.surface()) == Some("Moskvy")
+        };
+        assert!(lex.get_lexeme(&morph).iter().all(is_translit));
+        assert!(is_translit(&lex.get_lemma(&morph)));
+        let datv = lex.inflect(&morph, &GrammemeSet::new("datv")).unwrap();
+        assert_eq!(datv.get_word(), "москве");
+        assert!(is_translit(&datv));
+
+        assert!(morph.parse("pdf")[0].lex.get_tag(&morph).string == "LATN");
+        assert!(RU
+            .parse("privet")
+            .iter()
+            .all(|p| p.lex.get_tag(&RU).string == "LATN"));
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    analyzer::{units::abc::AnalyzerUnit, MorphAnalyzer},
+    container::{
+        stack::StackSource, Dictionary, Lex, ParseResult, Parsed, Score, SeenSet, UserWord,
+        WordStruct,
+    },
+    dawg::HH,
+    opencorpora::{lexicon::UserEntry, OpencorporaTagReg},
+};
+
+const USER_SCORE: Score = Score::Real(1.0);
+
+/// Looks the word up in the user lexicon (see `MorphAnalyzer::lexicon`).
+#[derive(Default, Debug, Clone, Copy)]
+pub struct UserLexiconAnalyzer {}
+
+impl AnalyzerUnit for UserLexiconAnalyzer {
+    fn parse(
+        &self,
+        morph: &MorphAnalyzer,
+        result: &mut ParseResult,
+        word: &str,
+        word_lower: &str,
+        _seen_parses: &mut SeenSet,
+    ) {
+        log::trace!("UserLexiconAnalyzer::parse()");
+        log::trace!(r#" word = "{}", word_lower = "{}" "#, word, word_lower);
+
+        for (form, entry, _) in self.lookup(morph, word_lower) {
+            let word_lower = WordStruct::known(form.as_str());
+            let source = match *entry {
+                UserEntry::Paradigm(para_id, idx) => {
+                    StackSource::from(Dictionary::new(word_lower, para_id, idx))
+                }
+                UserEntry::Lexeme(lexeme_id, idx) => {
+                    StackSource::from(UserWord::new(word_lower, lexeme_id, idx))
+                }
+            };
+            let lex = Lex::from_stack(morph, source);
+            result.push(Parsed::new(lex, USER_SCORE));
+        }
+    }
+
+    fn tag<'m>(
+        &self,
+        morph: &'m MorphAnalyzer,
+        tags: &mut Vec<&'m OpencorporaTagReg>,
+        word: &str,
+        word_lower: &str,
+        _seen_parses: &mut SeenSet,
+    ) {
+        log::trace!("UserLexiconAnalyzer::tag()");
+        log::trace!(r#" word = "{}", word_lower = "{}" "#, word, word_lower);
+
+        for (_, _, tag) in self.lookup(morph, word_lower) {
+            tags.push(tag);
+        }
+    }
+}
+
+impl UserLexiconAnalyzer {
+    /// Returns entries of the word with their tags.
+    ///
+    /// Forms which are in the dictionary with the same tag already (and so are found
+    /// by `DictionaryAnalyzer`) and repeated forms with the same tag are skipped.
+    ///
+    /// The lexicon index ignores the direction of the substitutes ("е" and "ё" are the same),
+    /// so entries not allowed by `MorphAnalyzer::char_substitutes` are skipped here.
+    fn lookup<'m>(
+        &self,
+        morph: &'m MorphAnalyzer,
+        word_lower: &str,
+    ) -> Vec<(&'m String, &'m UserEntry, &'m OpencorporaTagReg)> {
+        let mut found: Vec<(&String, &UserEntry, &OpencorporaTagReg)> = Vec::new();
+        for (form, entry) in morph.lexicon.lookup(&morph.char_substitutes, word_lower) {
+            if !is_substituted(word_lower, form, &morph.char_substitutes) {
+                continue;
+            }
+            let tag = match *entry {
+                UserEntry::Paradigm(para_id, idx) => morph.dict.get_tag(para_id, idx),
+                UserEntry::Lexeme(lexeme_id, idx) => morph.lexicon.get_tag(lexeme_id, idx),
+            };
+            let repeated = found.iter().any(|&(f, _, t)| f == form && t == tag);
+            if !repeated && !in_dictionary(morph, form, tag) {
+                found.push((form, entry, tag));
+            }
+        }
+        found
+    }
+}
+
+/// Checks the dictionary has `form` with `tag`.
+fn in_dictionary(morph: &MorphAnalyzer, form: &str, tag: &OpencorporaTagReg) -> bool {
+    morph
+        .dict
+        .words
+        .similar_items(form, &BTreeMap::new())
+        .into_iter()
+        .flat_map(|(_, parses)| parses)
+        .any(|HH(para_id, idx)| morph.dict.get_tag(para_id.into(), idx.into()) == tag)
+}
+
+/// Checks `form` can be written as `word` using the substitutes.
+fn is_substituted(word: &str, form: &str, char_substitutes: &BTreeMap<String, String>) -> bool {
+    let mut buf = [0; 4];
+    word.chars().count() == form.chars().count()
+        && word.chars().zip(form.chars()).all(|(w, f)| {
+            w == f
+                || char_substitutes
+                    .get(&*w.encode_utf8(&mut buf))
+                    .map_or(false, |to| to.chars().eq(Some(f)))
+        })
+}
@@ -2,13 +2,25 @@ use std::str::FromStr;
 
 use crate::{
     analyzer::{units::abc::AnalyzerUnit, MorphAnalyzer},
-    container::{abc::*, stack::StackSource, Lex, ParseResult, Parsed, SeenSet, Shaped},
+    container::{
+        abc::*,
+        shape::{SPELLED_NUMBER_FORMS, SUFFIXED_NUMBER_FORMS},
+        stack::StackSource,
+        Lex, ParseResult, Parsed, SeenSet, Shaped,
+    },
+    numbers::NumeralLexemes,
     opencorpora::OpencorporaTagReg,
 };
 
 /// This analyzer marks integer numbers with "NUMB,int" or "NUMB,real" tags.
 ///
-/// Example: "42" -> NUMB,intg; "3.14" -> NUMB,real
+/// Example: "42" -> NUMB,intg; "3.14" -> NUMB,real; "3,14" -> NUMB,real
+///
+/// Numbers with inflectional suffixes are tagged as ordinals (ADJF,Anum) or cardinals (NUMR):
+/// "5-го" -> ADJF,Anum masc,sing,gent; "5-ти" -> NUMR gent; "1990-х" -> ADJF,Anum plur,gent
+///
+/// Lexemes of integers also hold the number spelled out in every case, tagged "NUMB,intg"
+/// with the case and the gender: `Lex::inflect` turns "21" into "двадцати одному" in datv.
 ///
 /// .. note::
 ///
@@ -18,6 +30,12 @@ use crate::{
 pub struct NumberAnalyzer {
     pub tag_int: OpencorporaTagReg,
     pub tag_real: OpencorporaTagReg,
+    /// Tags of `SUFFIXED_NUMBER_FORMS`, shared with `RomanAnalyzer`
+    pub suffixed_tags: Vec<OpencorporaTagReg>,
+    /// Tags of `SPELLED_NUMBER_FORMS`
+    pub spelled_tags: Vec<OpencorporaTagReg>,
+    /// Lexemes of the words the spelled forms are made of
+    pub numerals: NumeralLexemes,
 }
 
 impl Default for NumberAnalyzer {
@@ -25,6 +43,15 @@ impl Default for NumberAnalyzer {
         NumberAnalyzer {
             tag_int: OpencorporaTagReg::new("NUMB,intg"),
             tag_real: OpencorporaTagReg::new("NUMB,real"),
+            suffixed_tags: SUFFIXED_NUMBER_FORMS
+                .iter()
+                .map(|&(_, _, tag)| OpencorporaTagReg::new(tag))
+                .collect(),
+            spelled_tags: SPELLED_NUMBER_FORMS
+                .iter()
+                .map(|&tag| OpencorporaTagReg::new(tag))
+                .collect(),
+            numerals: NumeralLexemes::default(),
         }
     }
 }
@@ -43,14 +70,16 @@ impl AnalyzerUnit for NumberAnalyzer {
 
         // TODO Improve number parser [#12]
         let shaped = if i128::from_str(word_lower).is_ok() {
-            Shaped::number(word_lower, false)
-        } else if f64::from_str(word_lower).is_ok() {
-            Shaped::number(word_lower, true)
+            vec![Shaped::number(word_lower, false)]
+        } else if f64::from_str(&word_lower.replacen(',', ".", 1)).is_ok() {
+            vec![Shaped::number(word_lower, true)]
         } else {
-            return;
+            Shaped::suffixed_numbers(word_lower, false)
         };
-        let score = shaped.score();
-        let lex = Lex::from_stack(morph, StackSource::from(shaped));
-        result.push(Parsed::new(lex, score));
+        for shaped in shaped {
+            let score = shaped.score();
+            let lex = Lex::from_stack(morph, StackSource::from(shaped));
+            result.push(Parsed::new(lex, score));
+        }
     }
 }
@@ -29,12 +29,16 @@ impl AnalyzerUnit for RomanAnalyzer {
     ) {
         log::trace!("RomanAnalyzer::parse()");
         log::trace!(r#" word = "{}", word_lower = "{}" "#, word, word_lower);
-        if !is_roman_number(word_lower) {
-            return;
+        let shaped = if is_roman_number(word_lower) {
+            vec![Shaped::roman_number(word_lower)]
+        } else {
+            // Ordinals with suffixes: "XXI-го", keeping the case of the number
+            Shaped::suffixed_numbers(word, true)
+        };
+        for shaped in shaped {
+            let score = shaped.score();
+            let lex = Lex::from_stack(morph, StackSource::from(shaped));
+            result.push(Parsed::new(lex, score));
         }
-        let shaped = Shaped::roman_number(word_lower);
-        let score = shaped.score();
-        let lex = Lex::from_stack(morph, StackSource::from(shaped));
-        result.push(Parsed::new(lex, score));
     }
 }
@@ -11,8 +11,11 @@ pub mod unknown;
 pub use self::abc::AnalyzerUnit;
 
 pub use self::abbr::initials::InitialsAnalyzer;
+pub use self::abbr::list::AbbreviationAnalyzer;
 
 pub use self::by_lookup::dict::DictionaryAnalyzer;
+pub use self::by_lookup::translit::TranslitAnalyzer;
+pub use self::by_lookup::user::UserLexiconAnalyzer;
 
 pub use self::by_hyphen::ha::HyphenAdverbAnalyzer;
 pub use self::by_hyphen::hsp::HyphenSeparatedParticleAnalyzer;
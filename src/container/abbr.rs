@@ -0,0 +1,127 @@
+use std::{borrow::Cow, fmt};
+
+use crate::{
+    analyzer::MorphAnalyzer,
+    container::{abc::*, decode::*, paradigm::ParadigmId, stack::StackSource, Lex, Score},
+    opencorpora::tag::OpencorporaTagReg,
+};
+
+/// A form of an abbreviation from `MorphAnalyzer::abbreviations`;
+/// the word is the same in all forms.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Abbreviation {
+    word: String,
+    id: usize,
+    idx: usize,
+}
+
+impl Abbreviation {
+    pub fn new<S>(word: S, id: usize, idx: usize) -> Self
+    where
+        S: Into<String>,
+    {
+        let word = word.into();
+        Abbreviation { word, id, idx }
+    }
+
+    /// The id of the entry in `MorphAnalyzer::abbreviations`.
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// The index of the tag in the entry.
+    pub fn idx(&self) -> usize {
+        self.idx
+    }
+
+    pub fn iter_lexeme<'s: 'i, 'm: 'i, 'i>(
+        &'s self,
+        morph: &'m MorphAnalyzer,
+    ) -> impl Iterator<Item = Lex> + 'i {
+        let entry = morph.abbreviations.get(self.id);
+        (0..entry.tags.len()).map(move |idx| {
+            Lex::from_stack(
+                morph,
+                StackSource::from(Abbreviation::new(entry.abbr.as_str(), self.id, idx)),
+            )
+        })
+    }
+}
+
+impl Source for Abbreviation {
+    fn score(&self) -> Score {
+        Score::Real(1.0)
+    }
+
+    fn is_lemma(&self) -> bool {
+        self.idx == 0
+    }
+
+    fn is_known(&self) -> bool {
+        true
+    }
+
+    fn get_word(&self) -> Cow<str> {
+        Cow::from(self.word.as_str())
+    }
+
+    fn get_normal_form(&self, _morph: &MorphAnalyzer) -> Cow<str> {
+        Cow::from(self.word.as_str())
+    }
+
+    fn get_tag<'m>(&self, morph: &'m MorphAnalyzer) -> &'m OpencorporaTagReg {
+        &morph.abbreviations.get(self.id).tags[self.idx]
+    }
+
+    fn try_get_para_id(&self) -> Option<ParadigmId> {
+        None
+    }
+
+    fn write_word<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
+        write!(f, "{}", self.word)
+    }
+
+    fn write_normal_form<W: fmt::Write>(&self, f: &mut W, _morph: &MorphAnalyzer) -> fmt::Result {
+        write!(f, "{}", self.word)
+    }
+
+    fn get_lexeme(&self, morph: &MorphAnalyzer) -> Vec<Lex> {
+        self.iter_lexeme(morph).collect()
+    }
+
+    fn get_lemma(&self, morph: &MorphAnalyzer) -> Lex {
+        self.iter_lexeme(morph).next().unwrap()
+    }
+}
+
+impl MorphySerde for Abbreviation {
+    fn encode<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
+        write!(f, "a:{},{:x}", self.word, self.id)?;
+        if self.idx != 0 {
+            write!(f, ",{:x}", self.idx)?;
+        }
+        Ok(())
+    }
+
+    /**
+        ```
+        use rsmorphy::container::Abbreviation;
+        use rsmorphy::container::abc::*;
+
+        assert_eq!(Abbreviation::decode(r"a:т.е.,0"), Ok(("", Abbreviation::new("т.е.", 0, 0))));
+        assert_eq!(Abbreviation::decode(r"a:руб.,1a,b"), Ok(("", Abbreviation::new("руб.", 0x1a, 0xb))));
+        assert_eq!(Abbreviation::decode(r"a:руб."), Err(DecodeError::UnexpectedEnd));
+        ```
+    */
+    fn decode(s: &str) -> Result<(&str, Self), DecodeError> {
+        let s = follow_str(s, "a").map_err(|_| DecodeError::UnknownPartType)?;
+        let (s, word) = take_str_until_char_is(follow_str(s, ":")?, ',')?;
+        let (s, id) = take_str_while_char(follow_str(s, ",")?, is_hex_digit)
+            .and_then(parse_hex_int::<usize>)?;
+        let (s, idx) = follow_str(s, ",")
+            .ok()
+            .map(|s| take_str_while_char(s, is_hex_digit).and_then(parse_hex_int::<usize>))
+            .unwrap_or_else(|| Ok((s, 0)))?;
+        Ok((s, Abbreviation::new(word, id, idx)))
+    }
+}
@@ -50,6 +50,15 @@ impl Dictionary {
         &'s self,
         morph: &'m MorphAnalyzer,
     ) -> impl Iterator<Item = Lex> + 'i {
+        self.iter_forms(morph)
+            .map(move |dict| Lex::from_stack(morph, StackSource::from(dict)))
+    }
+
+    /// Forms of the paradigm of the word, as `iter_lexeme` without the `Lex` wrapping.
+    pub fn iter_forms<'s: 'i, 'm: 'i, 'i>(
+        &'s self,
+        morph: &'m MorphAnalyzer,
+    ) -> impl Iterator<Item = Dictionary> + 'i {
         let paradigm = morph.dict.get_paradigm(self.para_id);
         let stem = morph
             .dict
@@ -69,10 +78,7 @@ impl Dictionary {
                     format!("{}{}{}", prefix, stem, suffix),
                     self.word_lower.is_known(),
                 );
-                Lex::from_stack(
-                    morph,
-                    StackSource::from(Dictionary::new(word, self.para_id, idx)),
-                )
+                Dictionary::new(word, self.para_id, idx)
             },
         )
     }
@@ -3,29 +3,41 @@ use std::{borrow::Cow, fmt};
 use crate::{
     analyzer::MorphAnalyzer,
     container::{abc::*, decode::*, paradigm::ParadigmId, stack::StackParticle, Score, Seen},
-    opencorpora::{GrammemeSet, OpencorporaTagReg},
+    language::Language,
+    opencorpora::{
+        kind::{case::Case, pos::PartOfSpeach},
+        GrammemeSet, Numeral, OpencorporaTagReg,
+    },
 };
 
 pub type Lexeme = Vec<Lex>;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Lex {
+    pub language: Language,
     pub stack: StackParticle,
 }
 
 impl Lex {
-    pub fn from_id<S>(_morph: &MorphAnalyzer, id: S) -> Result<Self, DecodeError>
+    /// Decodes the id; ids of another language than the one of the analyzer
+    /// or referring to absent user words or abbreviations don't match.
+    pub fn from_id<S>(morph: &MorphAnalyzer, id: S) -> Result<Self, DecodeError>
     where
         S: AsRef<str>,
     {
-        Ok(Self::decode(id.as_ref()).map(|(_, lex)| lex)?)
+        let (_, lex) = Self::decode(id.as_ref())?;
+        if lex.language != morph.language() || !lex.stack.stack.is_valid(morph) {
+            return Err(DecodeError::DoesntMatch);
+        }
+        Ok(lex)
     }
 
-    pub fn from_stack<S>(_morph: &MorphAnalyzer, stack: S) -> Self
+    pub fn from_stack<S>(morph: &MorphAnalyzer, stack: S) -> Self
     where
         S: Into<StackParticle>,
     {
         Lex {
+            language: morph.dict.language,
             stack: stack.into(),
         }
     }
@@ -45,10 +57,53 @@ impl Lex {
         }
     }
 
-    pub fn get_plural<'m>(&self, morph: &'m MorphAnalyzer, num: usize) -> Option<Lex> {
+    /// Returns the form agreeing with the number: any integer, `f64` or `Numeral`.
+    pub fn get_plural<N>(&self, morph: &MorphAnalyzer, num: N) -> Option<Lex>
+    where
+        N: Into<Numeral>,
+    {
         self.inflect(morph, &self.get_tag(morph).numeral_agreement_grammemes(num))
     }
 
+    /// Returns forms of a noun phrase agreeing with the number: "2 новых сообщения".
+    ///
+    /// The head noun and the words agreeing with it are chosen the same way as
+    /// by `inflect_phrase`; other words, e.g. dependents of the head ("2 сообщения директора"),
+    /// are kept as they are. Returns `None` if there is no noun or a word can't be inflected.
+    pub fn get_plural_phrase<N>(morph: &MorphAnalyzer, phrase: &[Lex], num: N) -> Option<Vec<Lex>>
+    where
+        N: Into<Numeral>,
+    {
+        let num = num.into();
+        let head = phrase_head(morph, phrase)?;
+        let head_tag = phrase[head].get_tag(morph);
+        let inflected = phrase[head].get_plural(morph, num)?;
+        inflect_by_head(morph, phrase, head, inflected, |lex, tag| {
+            lex.inflect(morph, &tag.modifier_agreement_grammemes(num, head_tag))
+        })
+    }
+
+    /// Inflects a phrase by its head noun: "красная площадь" → "красной площади".
+    ///
+    /// The head is the first noun not in an oblique case, so "директор школы" is headed
+    /// by "директор"; if all nouns are in oblique cases, the first one.
+    ///
+    /// Adjectives and participles preceding the head and agreeing with it take its new number,
+    /// case and gender; other words, e.g. genitive dependents ("министерство иностранных дел"),
+    /// are kept as they are. Returns `None` if there is no noun or a word can't be inflected.
+    pub fn inflect_phrase(
+        morph: &MorphAnalyzer,
+        phrase: &[Lex],
+        required: &GrammemeSet,
+    ) -> Option<Vec<Lex>> {
+        let head = phrase_head(morph, phrase)?;
+        let inflected = phrase[head].inflect(morph, required)?;
+        let inflected_tag = inflected.get_tag(morph);
+        inflect_by_head(morph, phrase, head, inflected, |lex, tag| {
+            lex.inflect(morph, &tag.head_agreement_grammemes(inflected_tag))
+        })
+    }
+
     pub fn inflect(&self, morph: &MorphAnalyzer, required: &GrammemeSet) -> Option<Lex> {
         let new_grammemes = self.get_tag(morph).prepare_required(morph, required);
         self.iter_lexeme(morph)
@@ -66,6 +121,43 @@ impl Lex {
     }
 }
 
+/// Returns the index of the head noun of a phrase (see `Lex::inflect_phrase`).
+fn phrase_head(morph: &MorphAnalyzer, phrase: &[Lex]) -> Option<usize> {
+    let is_noun = |lex: &Lex| lex.get_tag(morph).pos == Some(PartOfSpeach::Noun);
+    let is_oblique = |lex: &Lex| {
+        let case = lex.get_tag(morph).case.map(Case::main);
+        !matches!(case, None | Some(Case::Nomn) | Some(Case::Accs))
+    };
+    phrase
+        .iter()
+        .position(|lex| is_noun(lex) && !is_oblique(lex))
+        .or_else(|| phrase.iter().position(is_noun))
+}
+
+/// Replaces the head of a phrase with its `inflected` form and inflects the words
+/// preceding and agreeing with it by `agree`.
+fn inflect_by_head<F>(
+    morph: &MorphAnalyzer,
+    phrase: &[Lex],
+    head: usize,
+    inflected: Lex,
+    agree: F,
+) -> Option<Vec<Lex>>
+where
+    F: Fn(&Lex, &OpencorporaTagReg) -> Option<Lex>,
+{
+    let head_tag = phrase[head].get_tag(morph);
+    let mut result = phrase.to_vec();
+    for lex in &mut result[..head] {
+        let tag = lex.get_tag(morph);
+        if tag.agrees_with(head_tag) {
+            *lex = agree(lex, tag)?;
+        }
+    }
+    result[head] = inflected;
+    Some(result)
+}
+
 impl Source for Lex {
     fn score(&self) -> Score {
         self.stack.score()
@@ -120,13 +212,145 @@ impl fmt::Display for Lex {
 
 impl MorphySerde for Lex {
     fn encode<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
-        write!(f, "ru:")?;
+        write!(f, "{}:", self.language.code())?;
         self.stack.encode(f)
     }
 
+    /**
+        ```
+        use rsmorphy::container::abc::*;
+        use rsmorphy::container::{Lex, Unknown};
+        use rsmorphy::container::stack::{StackParticle, StackSource};
+        use rsmorphy::Language;
+
+        let (s, lex) = Lex::decode(r"uk:u:слово").unwrap();
+        assert_eq!(s, "");
+        assert_eq!(lex.language, Language::Ukrainian);
+        assert_eq!(lex.stack, StackParticle::from(StackSource::from(Unknown::new("слово"))));
+        assert_eq!(lex.encoded(), r"uk:u:слово");
+
+        assert_eq!(Lex::decode(r"xx:u:слово"), Err(DecodeError::UnknownPartType));
+        ```
+    */
     fn decode(s: &str) -> Result<(&str, Self), DecodeError> {
-        let s = follow_str(s, "ru").map_err(|_| DecodeError::UnknownPartType)?;
+        let (s, code) = take_str_until_char_is(s, ':').map_err(|_| DecodeError::UnknownPartType)?;
+        let language = Language::from_code(code).ok_or(DecodeError::UnknownPartType)?;
         let (s, stack) = StackParticle::decode(follow_str(s, ":")?)?;
-        Ok((s, Lex { stack }))
+        Ok((s, Lex { language, stack }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        opencorpora::{GrammemeSet, Numeral},
+        MorphAnalyzer,
+    };
+
+    lazy_static::lazy_static! {
+        static ref RU: MorphAnalyzer = MorphAnalyzer::from_file(dict_ru::DICT_PATH);
+    }
+
+    #[test]
+    fn get_plural() {
+        let plural = |word: &str, num: Numeral| -> String {
+            let lex = &RU.parse(word)[0].lex;
+            lex.get_plural(&RU, num).unwrap().get_word().into_owned()
+        };
+        let rouble = &RU.parse("рубль")[0].lex;
+        assert_eq!(rouble.get_plural(&RU, 5).unwrap().get_word(), "рублей");
+        assert_eq!(rouble.get_plural(&RU, -1).unwrap().get_word(), "рубль");
+        assert_eq!(rouble.get_plural(&RU, -22_i64).unwrap().get_word(), "рубля");
+        assert_eq!(
+            rouble.get_plural(&RU, u128::MAX).unwrap().get_word(),
+            "рублей"
+        );
+        assert_eq!(rouble.get_plural(&RU, 1.5).unwrap().get_word(), "рубля");
+        assert_eq!(plural("литр", "2,75".parse().unwrap()), "литра");
+        assert_eq!(plural("минута", "полторы".parse().unwrap()), "минуты");
+        assert_eq!(plural("сестра", "обе".parse().unwrap()), "сестры");
+
+        // Oblique cases keep the case and take plural: "полутора рублям", "о полутора рублях"
+        for &(case, expected) in &[("datv", "рублям"), ("loct", "рублях")] {
+            let form = rouble.inflect(&RU, &GrammemeSet::new(case)).unwrap();
+            assert_eq!(form.get_plural(&RU, 1.5).unwrap().get_word(), expected);
+        }
+    }
+
+    /// Lexes of the words of a phrase, in nominative where possible.
+    fn nominative_phrase(phrase: &str) -> Vec<Lex> {
+        phrase
+            .split(' ')
+            .map(|word| {
+                let parses = RU.parse(word);
+                let nomn = parses
+                    .iter()
+                    .find(|p| p.lex.get_tag(&RU).string.contains("nomn"))
+                    .unwrap_or(&parses[0]);
+                nomn.lex.clone()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn get_plural_phrase() {
+        let plural = |phrase: &str, num: Numeral| -> String {
+            let lexemes = nominative_phrase(phrase);
+            Lex::get_plural_phrase(&RU, &lexemes, num)
+                .unwrap()
+                .iter()
+                .map(|lex| lex.get_word().into_owned())
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        assert_eq!(plural("новое сообщение", 2.into()), "новых сообщения");
+        assert_eq!(
+            plural("непрочитанное письмо", 5.into()),
+            "непрочитанных писем"
+        );
+        assert_eq!(
+            plural("активный пользователь", 21.into()),
+            "активный пользователь"
+        );
+        assert_eq!(plural("новая книга", 3.into()), "новые книги");
+        assert_eq!(plural("новая книга", 1.into()), "новая книга");
+        assert_eq!(plural("очень новая книга", 1.5.into()), "очень новых книги");
+        assert_eq!(
+            plural("приказ нового директора", 2.into()),
+            "приказа нового директора"
+        );
+        // An adjective preceding the head but depending on another noun is kept
+        assert_eq!(
+            plural("новых технологий центр", 1.into()),
+            "новых технологий центр"
+        );
+        assert!(Lex::get_plural_phrase(&RU, &[RU.parse("новый")[0].lex.clone()], 2).is_none());
+    }
+
+    #[test]
+    fn inflect_phrase() {
+        let inflect = |phrase: &str, grammemes: &str| -> String {
+            let lexemes = nominative_phrase(phrase);
+            Lex::inflect_phrase(&RU, &lexemes, &GrammemeSet::new(grammemes))
+                .unwrap()
+                .iter()
+                .map(|lex| lex.get_word().into_owned())
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        assert_eq!(inflect("красная площадь", "loct"), "красной площади");
+        assert_eq!(inflect("красная площадь", "plur,gent"), "красных площадей");
+        assert_eq!(inflect("новый учебный год", "datv"), "новому учебному году");
+        assert_eq!(inflect("новый студент", "accs"), "нового студента");
+        assert_eq!(
+            inflect("министерство иностранных дел", "ablt"),
+            "министерством иностранных дел"
+        );
+        assert_eq!(
+            inflect("новый директор школы", "datv"),
+            "новому директору школы"
+        );
+        assert!(Lex::inflect_phrase(&RU, &[], &GrammemeSet::new("gent")).is_none());
     }
 }
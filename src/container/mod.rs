@@ -1,5 +1,7 @@
 pub mod abc;
 
+pub mod abbr;
+
 pub mod word;
 
 pub mod dict;
@@ -10,7 +12,9 @@ pub mod ha;
 pub mod hyphen;
 pub mod initials;
 pub mod shape;
+pub mod translit;
 pub mod unknown;
+pub mod user;
 
 pub mod lex;
 pub mod parsed;
@@ -22,6 +26,8 @@ pub mod decode;
 
 pub use self::word::WordStruct;
 
+pub use self::abbr::Abbreviation;
+
 pub use self::dict::Dictionary;
 
 pub use self::initials::Initials;
@@ -36,7 +42,9 @@ pub use self::affix::AffixKind;
 pub use self::shape::ShapeKind;
 pub use self::shape::Shaped;
 
+pub use self::translit::Transliterated;
 pub use self::unknown::Unknown;
+pub use self::user::UserWord;
 
 pub use self::lex::Lex;
 pub use self::parsed::ParseResult;
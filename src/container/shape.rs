@@ -1,9 +1,14 @@
-use std::{borrow::Cow, fmt};
+use std::{borrow::Cow, fmt, str::FromStr};
 
 use crate::{
     analyzer::MorphAnalyzer,
     container::{abc::*, decode::*, paradigm::ParadigmId, stack::StackSource, Lex, Score},
-    opencorpora::OpencorporaTagReg,
+    numbers::{cardinal, NumeralForm},
+    opencorpora::{
+        kind::{animacy::Animacy, case::Case, gender::Gender},
+        OpencorporaTagReg,
+    },
+    shapes::is_roman_number,
 };
 
 const NUMBER_SCORE: Score = Score::Real(1.0);
@@ -11,10 +16,142 @@ const DECAYED_SCORE: Score = Score::Fake(0.9);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum ShapeKind {
-    Number { is_float: bool },
+    Number {
+        is_float: bool,
+    },
     RomanNumber,
     Latin,
     Punctuation,
+    /// A number with an inflectional suffix: "5-й", "1990-х", "XXI-го";
+    /// `form` is the index in `SUFFIXED_NUMBER_FORMS`
+    SuffixedNumber {
+        is_roman: bool,
+        form: u8,
+    },
+    /// An integer spelled out in words: "двадцати одному";
+    /// `form` is the index in `SPELLED_NUMBER_FORMS`
+    SpelledNumber {
+        value: i128,
+        form: u8,
+    },
+}
+
+/// Paradigms of numbers written with inflectional suffixes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum NumberParadigm {
+    /// Numbers ending in 2-4: "2-х", "2-мя" (двух, двумя)
+    FewCardinal,
+    /// Numbers ending in 5-9, 11-20, 30, 50-80: "5-ти", "5-ю" (пяти, пятью)
+    ManyCardinal,
+    /// "5-й", "5-го", "1990-х" (пятый, пятого, девяностых)
+    Ordinal,
+}
+
+/// Forms of numbers with inflectional suffixes: the paradigm, the suffix and the tag.
+///
+/// The first form of a paradigm is the normal one; cardinals in nominative have no suffix.
+pub const SUFFIXED_NUMBER_FORMS: &[(NumberParadigm, &str, &str)] = &[
+    (NumberParadigm::FewCardinal, "", "NUMR nomn"),
+    (NumberParadigm::FewCardinal, "х", "NUMR gent"),
+    (NumberParadigm::FewCardinal, "м", "NUMR datv"),
+    (NumberParadigm::FewCardinal, "", "NUMR inan,accs"),
+    (NumberParadigm::FewCardinal, "х", "NUMR anim,accs"),
+    (NumberParadigm::FewCardinal, "мя", "NUMR ablt"),
+    (NumberParadigm::FewCardinal, "х", "NUMR loct"),
+    (NumberParadigm::ManyCardinal, "", "NUMR nomn"),
+    (NumberParadigm::ManyCardinal, "ти", "NUMR gent"),
+    (NumberParadigm::ManyCardinal, "ти", "NUMR datv"),
+    (NumberParadigm::ManyCardinal, "", "NUMR accs"),
+    (NumberParadigm::ManyCardinal, "ю", "NUMR ablt"),
+    (NumberParadigm::ManyCardinal, "ти", "NUMR loct"),
+    (NumberParadigm::Ordinal, "й", "ADJF,Anum masc,sing,nomn"),
+    (NumberParadigm::Ordinal, "го", "ADJF,Anum masc,sing,gent"),
+    (NumberParadigm::Ordinal, "му", "ADJF,Anum masc,sing,datv"),
+    (
+        NumberParadigm::Ordinal,
+        "й",
+        "ADJF,Anum inan,masc,sing,accs",
+    ),
+    (
+        NumberParadigm::Ordinal,
+        "го",
+        "ADJF,Anum anim,masc,sing,accs",
+    ),
+    (NumberParadigm::Ordinal, "м", "ADJF,Anum masc,sing,ablt"),
+    (NumberParadigm::Ordinal, "м", "ADJF,Anum masc,sing,loct"),
+    (NumberParadigm::Ordinal, "я", "ADJF,Anum femn,sing,nomn"),
+    (NumberParadigm::Ordinal, "й", "ADJF,Anum femn,sing,gent"),
+    (NumberParadigm::Ordinal, "й", "ADJF,Anum femn,sing,datv"),
+    (NumberParadigm::Ordinal, "ю", "ADJF,Anum femn,sing,accs"),
+    (NumberParadigm::Ordinal, "й", "ADJF,Anum femn,sing,ablt"),
+    (NumberParadigm::Ordinal, "й", "ADJF,Anum femn,sing,loct"),
+    (NumberParadigm::Ordinal, "е", "ADJF,Anum neut,sing,nomn"),
+    (NumberParadigm::Ordinal, "го", "ADJF,Anum neut,sing,gent"),
+    (NumberParadigm::Ordinal, "му", "ADJF,Anum neut,sing,datv"),
+    (NumberParadigm::Ordinal, "е", "ADJF,Anum neut,sing,accs"),
+    (NumberParadigm::Ordinal, "м", "ADJF,Anum neut,sing,ablt"),
+    (NumberParadigm::Ordinal, "м", "ADJF,Anum neut,sing,loct"),
+    (NumberParadigm::Ordinal, "е", "ADJF,Anum plur,nomn"),
+    (NumberParadigm::Ordinal, "х", "ADJF,Anum plur,gent"),
+    (NumberParadigm::Ordinal, "м", "ADJF,Anum plur,datv"),
+    (NumberParadigm::Ordinal, "е", "ADJF,Anum inan,plur,accs"),
+    (NumberParadigm::Ordinal, "х", "ADJF,Anum anim,plur,accs"),
+    (NumberParadigm::Ordinal, "ми", "ADJF,Anum plur,ablt"),
+    (NumberParadigm::Ordinal, "х", "ADJF,Anum plur,loct"),
+];
+
+/// Tags of the forms of integers spelled out (see `numbers::cardinal`).
+///
+/// `Lex::inflect` takes the last of the forms matching equally well,
+/// so masculine and inanimate forms go last to be chosen by default.
+pub const SPELLED_NUMBER_FORMS: &[&str] = &[
+    "NUMB,intg neut,nomn",
+    "NUMB,intg femn,nomn",
+    "NUMB,intg masc,nomn",
+    "NUMB,intg neut,gent",
+    "NUMB,intg femn,gent",
+    "NUMB,intg masc,gent",
+    "NUMB,intg neut,datv",
+    "NUMB,intg femn,datv",
+    "NUMB,intg masc,datv",
+    "NUMB,intg anim,neut,accs",
+    "NUMB,intg inan,neut,accs",
+    "NUMB,intg anim,femn,accs",
+    "NUMB,intg inan,femn,accs",
+    "NUMB,intg anim,masc,accs",
+    "NUMB,intg inan,masc,accs",
+    "NUMB,intg neut,ablt",
+    "NUMB,intg femn,ablt",
+    "NUMB,intg masc,ablt",
+    "NUMB,intg neut,loct",
+    "NUMB,intg femn,loct",
+    "NUMB,intg masc,loct",
+];
+
+/// Paradigms a number may be inflected by; Roman numbers are ordinals only.
+fn number_paradigms(number: &str, is_roman: bool) -> Vec<NumberParadigm> {
+    let mut result = Vec::new();
+    if !is_roman {
+        let last_digits: u8 = number[number.len().saturating_sub(2)..]
+            .parse()
+            .unwrap_or(0);
+        match (last_digits / 10, last_digits % 10) {
+            (1, _) | (2, 0) | (3, 0) | (5..=8, 0) | (_, 5..=9) => {
+                result.push(NumberParadigm::ManyCardinal)
+            }
+            (_, 2..=4) => result.push(NumberParadigm::FewCardinal),
+            _ => {}
+        }
+    }
+    result.push(NumberParadigm::Ordinal);
+    result
+}
+
+fn with_suffix(number: &str, suffix: &str) -> String {
+    match suffix {
+        "" => number.to_owned(),
+        _ => format!("{}-{}", number, suffix),
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -60,24 +197,157 @@ impl Shaped {
         Shaped::new(word, ShapeKind::Punctuation)
     }
 
+    /// Forms of a number with an inflectional suffix matching the word, cardinals first:
+    /// "5-го" is an ordinal in masculine or neuter genitive or in masculine accusative.
+    ///
+    /// The number is either Arabic or Roman (in any case), as `is_roman` says;
+    /// a Roman number keeps its capitalization in all the forms ("XXI-го", "XXI-м").
+    pub fn suffixed_numbers(word: &str, is_roman: bool) -> Vec<Shaped> {
+        let (number, suffix) = match word.find('-') {
+            Some(pos) => (&word[..pos], word[pos + 1..].to_lowercase()),
+            None => return Vec::new(),
+        };
+        let is_number = match is_roman {
+            true => is_roman_number(&number.to_uppercase()),
+            false => !number.is_empty() && number.bytes().all(|b| b.is_ascii_digit()),
+        };
+        if !is_number || suffix.is_empty() {
+            return Vec::new();
+        }
+        let paradigms = number_paradigms(number, is_roman);
+        SUFFIXED_NUMBER_FORMS
+            .iter()
+            .enumerate()
+            .filter(|&(_, &(paradigm, form_suffix, _))| {
+                form_suffix == suffix && paradigms.contains(&paradigm)
+            })
+            .map(|(form, _)| {
+                let form = form as u8;
+                let word = with_suffix(number, &suffix);
+                Shaped::new(word, ShapeKind::SuffixedNumber { is_roman, form })
+            })
+            .collect()
+    }
+
     pub fn iter_lexeme<'s: 'i, 'm: 'i, 'i>(
         &'s self,
         morph: &'m MorphAnalyzer,
     ) -> impl Iterator<Item = Lex> + 'i {
-        (0..1).map(move |_| Lex::from_stack(morph, StackSource::from(self.clone())))
+        let forms: Vec<Shaped> = match self.kind {
+            ShapeKind::SuffixedNumber { is_roman, form } => {
+                let paradigm = SUFFIXED_NUMBER_FORMS[form as usize].0;
+                let number = self.unsuffixed();
+                SUFFIXED_NUMBER_FORMS
+                    .iter()
+                    .enumerate()
+                    .filter(|&(_, &(p, _, _))| p == paradigm)
+                    .map(|(form, &(_, suffix, _))| {
+                        let kind = ShapeKind::SuffixedNumber {
+                            is_roman,
+                            form: form as u8,
+                        };
+                        Shaped::new(with_suffix(number, suffix), kind)
+                    })
+                    .collect()
+            }
+            ShapeKind::Number { is_float: false } | ShapeKind::SpelledNumber { .. } => {
+                match self.integer() {
+                    Some(value) => Shaped::integer_forms(morph, value),
+                    None => vec![self.clone()],
+                }
+            }
+            _ => vec![self.clone()],
+        };
+        forms
+            .into_iter()
+            .map(move |shaped| Lex::from_stack(morph, StackSource::from(shaped)))
+    }
+
+    /// Forms of an integer: the digits, the number spelled out in every case
+    /// ("двадцати одному") and, unless it's negative, the ordinal with suffixes ("21-му").
+    fn integer_forms(morph: &MorphAnalyzer, value: i128) -> Vec<Shaped> {
+        let digits = value.to_string();
+        let mut forms = vec![Shaped::number(digits.as_str(), false)];
+        let spelled = morph.units.number.spelled_tags.iter().enumerate();
+        forms.extend(spelled.map(|(form, tag)| {
+            let numeral_form = NumeralForm::new(
+                tag.case.unwrap_or(Case::Nomn),
+                tag.gender.unwrap_or(Gender::Masc),
+                tag.animacy.unwrap_or(Animacy::Inan),
+            );
+            let kind = ShapeKind::SpelledNumber {
+                value,
+                form: form as u8,
+            };
+            Shaped::new(cardinal(morph, value, numeral_form), kind)
+        }));
+        if value >= 0 {
+            let ordinals = SUFFIXED_NUMBER_FORMS
+                .iter()
+                .enumerate()
+                .filter(|&(_, &(paradigm, _, _))| paradigm == NumberParadigm::Ordinal);
+            forms.extend(ordinals.map(|(form, &(_, suffix, _))| {
+                let kind = ShapeKind::SuffixedNumber {
+                    is_roman: false,
+                    form: form as u8,
+                };
+                Shaped::new(with_suffix(&digits, suffix), kind)
+            }));
+        }
+        forms
+    }
+
+    /// The value of an integer written with digits or spelled out.
+    fn integer(&self) -> Option<i128> {
+        match self.kind {
+            ShapeKind::Number { is_float: false } => i128::from_str(&self.word).ok(),
+            ShapeKind::SpelledNumber { value, .. } => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The word without the suffix.
+    fn unsuffixed(&self) -> &str {
+        self.word.split('-').next().unwrap_or_default()
+    }
+
+    /// The first form of the paradigm of a suffixed number, the word itself otherwise.
+    fn normal_form(&self) -> Cow<'_, str> {
+        match self.kind {
+            ShapeKind::SuffixedNumber { form, .. } => {
+                let paradigm = SUFFIXED_NUMBER_FORMS[form as usize].0;
+                let suffix = SUFFIXED_NUMBER_FORMS
+                    .iter()
+                    .find(|&&(p, _, _)| p == paradigm)
+                    .map_or("", |&(_, suffix, _)| suffix);
+                Cow::from(with_suffix(self.unsuffixed(), suffix))
+            }
+            ShapeKind::SpelledNumber { value, .. } => Cow::from(value.to_string()),
+            _ => Cow::from(self.word.as_str()),
+        }
     }
 }
 
 impl Source for Shaped {
     fn score(&self) -> Score {
         match self.kind {
-            ShapeKind::Number { .. } => NUMBER_SCORE,
+            ShapeKind::Number { .. } | ShapeKind::SpelledNumber { .. } => NUMBER_SCORE,
             _ => DECAYED_SCORE,
         }
     }
 
     fn is_lemma(&self) -> bool {
-        true
+        match self.kind {
+            ShapeKind::SuffixedNumber { form, .. } => {
+                let paradigm = SUFFIXED_NUMBER_FORMS[form as usize].0;
+                SUFFIXED_NUMBER_FORMS
+                    .iter()
+                    .position(|&(p, _, _)| p == paradigm)
+                    == Some(form as usize)
+            }
+            ShapeKind::SpelledNumber { .. } => false,
+            _ => true,
+        }
     }
 
     fn is_known(&self) -> bool {
@@ -89,7 +359,7 @@ impl Source for Shaped {
     }
 
     fn get_normal_form(&self, _morph: &MorphAnalyzer) -> Cow<str> {
-        Cow::from(self.word.as_str())
+        self.normal_form()
     }
 
     fn get_tag<'a>(&self, morph: &'a MorphAnalyzer) -> &'a OpencorporaTagReg {
@@ -103,6 +373,8 @@ impl Source for Shaped {
                 true => &morph.units.number.tag_real,
                 false => &morph.units.number.tag_int,
             },
+            SuffixedNumber { form, .. } => &morph.units.number.suffixed_tags[form as usize],
+            SpelledNumber { form, .. } => &morph.units.number.spelled_tags[form as usize],
         }
     }
 
@@ -115,7 +387,7 @@ impl Source for Shaped {
     }
 
     fn write_normal_form<W: fmt::Write>(&self, f: &mut W, _morph: &MorphAnalyzer) -> fmt::Result {
-        write!(f, "{}", self.word)
+        write!(f, "{}", self.normal_form())
     }
 
     fn get_lexeme(&self, morph: &MorphAnalyzer) -> Vec<Lex> {
@@ -133,7 +405,7 @@ impl MorphySerde for Shaped {
 
         write!(
             f,
-            "s:{},",
+            "s:{}",
             match self.kind {
                 Latin => "l",
                 Number { is_float } => {
@@ -145,8 +417,17 @@ impl MorphySerde for Shaped {
                 }
                 Punctuation => "p",
                 RomanNumber => "r",
+                SuffixedNumber { .. } | SpelledNumber { .. } => "",
             },
         )?;
+        match self.kind {
+            SuffixedNumber { is_roman, form } => {
+                write!(f, "{}{}", if is_roman { "R" } else { "I" }, form)?
+            }
+            SpelledNumber { value, form } => write!(f, "W{}:{}", form, value)?,
+            _ => (),
+        }
+        write!(f, ",")?;
         for ch in escape(&self.word) {
             write!(f, "{}", ch)?;
         }
@@ -159,6 +440,26 @@ impl MorphySerde for Shaped {
         let s = follow_str(s, "s").map_err(|_| DecodeError::UnknownPartType)?;
         let s = follow_str(s, ":")?;
         let (s, kind) = take_1_char(s)?;
+        let (s, form) = match kind {
+            'I' | 'R' | 'W' => {
+                let (s, form) = parse_int(take_str_while_char(s, is_digit)?)?;
+                let forms = match kind {
+                    'W' => SPELLED_NUMBER_FORMS.len(),
+                    _ => SUFFIXED_NUMBER_FORMS.len(),
+                };
+                if form as usize >= forms {
+                    return Err(DecodeError::DoesntMatch);
+                }
+                (s, form)
+            }
+            _ => (s, 0),
+        };
+        let (s, value) = match kind {
+            'W' => parse_int(take_str_while_char(follow_str(s, ":")?, |ch| {
+                is_digit(ch) || ch == '-'
+            })?)?,
+            _ => (s, 0),
+        };
         // FIXME skip escaped ";"
         let (s, word) = take_str_until_char_is(follow_str(s, ",")?, ';')?;
         Ok((
@@ -170,6 +471,15 @@ impl MorphySerde for Shaped {
                     'i' => Number { is_float: false },
                     'p' => Punctuation,
                     'r' => RomanNumber,
+                    'I' => SuffixedNumber {
+                        is_roman: false,
+                        form,
+                    },
+                    'R' => SuffixedNumber {
+                        is_roman: true,
+                        form,
+                    },
+                    'W' => SpelledNumber { value, form },
                     _ => Err(DecodeError::UnknownPartType)?,
                 },
                 word: unescape(word).collect(),
@@ -177,3 +487,85 @@ impl MorphySerde for Shaped {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        container::{abc::*, Lex},
+        opencorpora::GrammemeSet,
+        MorphAnalyzer,
+    };
+
+    lazy_static::lazy_static! {
+        static ref RU: MorphAnalyzer = MorphAnalyzer::from_file(dict_ru::DICT_PATH);
+    }
+
+    #[test]
+    fn parse_suffixed_numbers() {
+        let inflect = |word: &str, tag: &str, grammemes: &str| -> String {
+            let parsed = RU.parse(word);
+            let lex = &parsed
+                .iter()
+                .find(|p| p.lex.get_tag(&RU).string == tag)
+                .unwrap()
+                .lex;
+            assert_eq!(Lex::from_id(&RU, lex.encoded()).as_ref(), Ok(lex));
+            let form = lex.inflect(&RU, &GrammemeSet::new(grammemes)).unwrap();
+            form.get_word().into_owned()
+        };
+        let tags = |word: &str| -> Vec<String> {
+            RU.parse(word)
+                .iter()
+                .map(|p| p.lex.get_tag(&RU).string.clone())
+                .collect()
+        };
+        assert_eq!(inflect("5-й", "ADJF,Anum masc,sing,nomn", "datv"), "5-му");
+        assert_eq!(inflect("5-й", "ADJF,Anum masc,sing,nomn", "femn"), "5-я");
+        assert_eq!(inflect("1990-е", "ADJF,Anum plur,nomn", "gent"), "1990-х");
+        assert_eq!(inflect("1990-х", "ADJF,Anum plur,gent", "ablt"), "1990-ми");
+        assert_eq!(inflect("5-ти", "NUMR gent", "ablt"), "5-ю");
+        assert_eq!(inflect("5-ти", "NUMR gent", "nomn"), "5");
+        assert_eq!(inflect("2-х", "NUMR gent", "datv"), "2-м");
+        assert_eq!(
+            inflect("XXI-го", "ADJF,Anum masc,sing,gent", "loct"),
+            "XXI-м"
+        );
+        assert_eq!(tags("5-ти"), ["NUMR gent", "NUMR datv", "NUMR loct"]);
+        assert_eq!(tags("2-х")[0], "NUMR gent");
+        assert!(!tags("1990-х").iter().any(|tag| tag.starts_with("NUMR")));
+        assert_eq!(RU.parse("1990-х")[0].lex.get_normal_form(&RU), "1990-й");
+    }
+
+    #[test]
+    fn inflect_numbers() {
+        let inflect = |word: &str, grammemes: &str| -> String {
+            let lex = &RU.parse(word)[0].lex;
+            let form = lex.inflect(&RU, &GrammemeSet::new(grammemes)).unwrap();
+            assert_eq!(Lex::from_id(&RU, form.encoded()).as_ref(), Ok(&form));
+            assert_eq!(form.get_normal_form(&RU), word);
+            form.get_word().into_owned()
+        };
+        assert_eq!(inflect("21", "datv"), "двадцати одному");
+        assert_eq!(inflect("21", "femn,datv"), "двадцати одной");
+        assert_eq!(inflect("2", "accs"), "два");
+        assert_eq!(inflect("2", "anim,accs"), "двух");
+        assert_eq!(inflect("5", "ablt"), "пятью");
+        assert_eq!(inflect("21", "ADJF,Anum,masc,sing,datv"), "21-му");
+        assert_eq!(inflect("3", "ADJF,Anum,femn,sing,nomn"), "3-я");
+        assert_eq!(inflect("-3", "gent"), "минус трёх");
+        assert_eq!(RU.parse("2.5")[0].lex.get_lexeme(&RU).len(), 1);
+
+        let noun = &RU.parse("рубль")[0].lex;
+        let required = GrammemeSet::new("datv");
+        let phrase = format!(
+            "к {} {}",
+            inflect("21", "datv"),
+            noun.get_plural(&RU, 21)
+                .unwrap()
+                .inflect(&RU, &required)
+                .unwrap()
+                .get_word()
+        );
+        assert_eq!(phrase, "к двадцати одному рублю");
+    }
+}
@@ -4,17 +4,24 @@ use crate::{
     analyzer::MorphAnalyzer,
     container::{
         abc::*,
+        decode::*,
         paradigm::ParadigmId,
         stack::{StackAffix, StackSource},
-        Lex, Score,
+        Lex, Score, Unknown,
+    },
+    opencorpora::{
+        kind::{Case, Number, PartOfSpeach},
+        OpencorporaTagReg,
     },
-    opencorpora::OpencorporaTagReg,
 };
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct StackHyphenated {
     pub left: StackAffix,
     pub right: Option<StackAffix>,
+    /// The left part is frozen and only the right part is inflected (интернет-магазин);
+    /// otherwise both parts are inflected together (человек-гора).
+    pub fixed_left: bool,
 }
 
 impl StackHyphenated {
@@ -23,25 +30,89 @@ impl StackHyphenated {
         R: Into<Option<StackAffix>>,
     {
         let right = right.into();
-        StackHyphenated { left, right }
+        StackHyphenated {
+            left,
+            right,
+            fixed_left: false,
+        }
+    }
+
+    /// Creates a word with the frozen `left` part.
+    pub fn with_fixed_left<L>(left: L, right: StackAffix) -> Self
+    where
+        L: Into<String>,
+    {
+        let left = StackAffix::from(StackSource::from(Unknown::new(left)));
+        StackHyphenated {
+            left,
+            right: Some(right),
+            fixed_left: true,
+        }
+    }
+
+    /// Returns a part which the tag of the whole word is taken from.
+    fn head(&self) -> &StackAffix {
+        match self.right {
+            Some(ref right) if self.fixed_left => right,
+            _ => &self.left,
+        }
+    }
+
+    /// Checks the sources of both parts (see `StackSource::is_valid`).
+    pub fn is_valid(&self, morph: &MorphAnalyzer) -> bool {
+        self.left.stack.is_valid(morph)
+            && self.right.iter().all(|right| right.stack.is_valid(morph))
     }
 
     pub fn iter_lexeme<'s: 'i, 'm: 'i, 'i>(
         &'s self,
         morph: &'m MorphAnalyzer,
-    ) -> impl Iterator<Item = Lex> + 'i {
-        self.left.iter_lexeme(morph).map(move |lex: Lex| {
-            Lex::from_stack(morph, StackHyphenated::new(lex.stack.stack.left, None))
-        })
+    ) -> Box<dyn Iterator<Item = Lex> + 'i> {
+        match self.right {
+            None => Box::new(self.left.iter_lexeme(morph).map(move |lex: Lex| {
+                Lex::from_stack(morph, StackHyphenated::new(lex.stack.stack.left, None))
+            })),
+            Some(ref right) if self.fixed_left => {
+                Box::new(right.iter_lexeme(morph).map(move |lex: Lex| {
+                    let container = StackHyphenated {
+                        left: self.left.clone(),
+                        right: Some(lex.stack.stack.left),
+                        fixed_left: true,
+                    };
+                    Lex::from_stack(morph, container)
+                }))
+            }
+            Some(ref right) => {
+                let left_lexeme: Vec<Lex> = self.left.iter_lexeme(morph).collect();
+                Box::new(right.iter_lexeme(morph).filter_map(move |right: Lex| {
+                    let right_features = similarity_features(right.get_tag(morph));
+                    let left = left_lexeme.iter().min_by_key(|left| {
+                        features_distance(similarity_features(left.get_tag(morph)), right_features)
+                    })?;
+                    let container =
+                        StackHyphenated::new(left.stack.stack.left.clone(), right.stack.stack.left);
+                    Some(Lex::from_stack(morph, container))
+                }))
+            }
+        }
     }
 }
 
+type SimilarityFeatures = (Option<PartOfSpeach>, Option<Number>, Option<Case>);
+
+/// Returns grammemes which must be the same for both parts of a word
+/// inflected together.
+pub fn similarity_features(tag: &OpencorporaTagReg) -> SimilarityFeatures {
+    (tag.pos, tag.number, tag.case.map(Case::main))
+}
+
+fn features_distance(a: SimilarityFeatures, b: SimilarityFeatures) -> usize {
+    (a.0 != b.0) as usize + (a.1 != b.1) as usize + (a.2 != b.2) as usize
+}
+
 impl From<StackAffix> for StackHyphenated {
     fn from(stack: StackAffix) -> Self {
-        StackHyphenated {
-            left: stack,
-            right: None,
-        }
+        StackHyphenated::new(stack, None)
     }
 }
 
@@ -59,6 +130,7 @@ impl Source for StackHyphenated {
     fn is_lemma(&self) -> bool {
         match self.right {
             None => self.left.is_lemma(),
+            Some(ref right) if self.fixed_left => right.is_lemma(),
             Some(ref right) => self.left.is_lemma() && right.is_lemma(),
         }
     }
@@ -66,6 +138,7 @@ impl Source for StackHyphenated {
     fn is_known(&self) -> bool {
         match self.right {
             None => self.left.is_known(),
+            Some(ref right) if self.fixed_left => right.is_known(),
             Some(ref right) => self.left.is_known() && right.is_known(),
         }
     }
@@ -90,22 +163,17 @@ impl Source for StackHyphenated {
     }
 
     fn get_tag<'m>(&self, morph: &'m MorphAnalyzer) -> &'m OpencorporaTagReg {
-        match self.right {
-            None => self.left.get_tag(morph),
-            Some(_) => unimplemented!(),
-        }
+        self.head().get_tag(morph)
     }
 
     fn try_get_para_id(&self) -> Option<ParadigmId> {
-        match self.right {
-            None => self.left.try_get_para_id(),
-            Some(_) => unimplemented!(),
-        }
+        self.head().try_get_para_id()
     }
 
     fn write_word<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
         self.left.write_word(f)?;
         if let Some(ref right) = self.right {
+            write!(f, "-")?;
             right.write_word(f)?;
         }
         Ok(())
@@ -114,6 +182,7 @@ impl Source for StackHyphenated {
     fn write_normal_form<W: fmt::Write>(&self, f: &mut W, morph: &MorphAnalyzer) -> fmt::Result {
         self.left.write_normal_form(f, morph)?;
         if let Some(ref right) = self.right {
+            write!(f, "-")?;
             right.write_normal_form(f, morph)?;
         }
         Ok(())
@@ -132,7 +201,7 @@ impl MorphySerde for StackHyphenated {
     fn encode<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
         match self.right {
             Some(ref right) => {
-                write!(f, "hw:")?;
+                write!(f, "{}:", if self.fixed_left { "hf" } else { "hw" })?;
                 self.left.encode(f)?;
                 write!(f, ";-")?;
                 right.encode(f)
@@ -141,30 +210,55 @@ impl MorphySerde for StackHyphenated {
         }
     }
 
+    /**
+        ```
+        use rsmorphy::container::abc::*;
+        use rsmorphy::container::stack::{StackAffix, StackHyphenated, StackSource};
+        use rsmorphy::container::{Dictionary, WordStruct};
+
+        let word = |word, para_id| {
+            let dict = Dictionary::new(WordStruct::known(word), para_id, 0_u16);
+            StackAffix::from(StackSource::from(dict))
+        };
+
+        assert_eq!(
+            StackHyphenated::decode(r"hw:d:человек,4;-d:гора,2a"),
+            Ok(("", StackHyphenated::new(word("человек", 0x4_u16), word("гора", 0x2a_u16))))
+        );
+
+        assert_eq!(
+            StackHyphenated::decode(r"hf:u:интернет;-d:магазин,1f"),
+            Ok(("", StackHyphenated::with_fixed_left("интернет", word("магазин", 0x1f_u16))))
+        );
+
+        assert_eq!(
+            StackHyphenated::decode(r"hw:d:человек,4"),
+            Err(DecodeError::UnexpectedEnd)
+        );
+        ```
+    */
     fn decode(s: &str) -> Result<(&str, Self), DecodeError> {
-        let (s, stack) = StackAffix::decode(s)?;
-        let result = (s, StackHyphenated::new(stack, None));
-        // TODO
-        //        if !s.is_empty() {
-        //            match (|s| {
-        //                let s = follow_str(s, ";")?;
-        //                let s = follow_str(s, "hw").map_err(|e| match e {
-        //                    DecodeError::DoesntMatch => DecodeError::UnknownPartType,
-        //                    _ => e,
-        //                })?;
-        //                let (s, word) = take_str_until_char_is(follow_str(s, ":")?, ';')?;
-        //                Ok((s, HyphenSeparatedParticle {
-        //                    particle: word.to_string(),
-        //                }))
-        //            })(s) {
-        //                Err(DecodeError::UnknownPartType) => (),
-        //                Err(e) => Err(e)?,
-        //                Ok((s, particle)) => {
-        //                    result.0 = s;
-        //                    result.1.particle = Some(particle);
-        //                },
-        //            };
-        //        }
-        Ok(result)
+        let hyphenated = follow_str(s, "hw")
+            .map(|s| (s, false))
+            .or_else(|_| follow_str(s, "hf").map(|s| (s, true)));
+        match hyphenated {
+            Err(_) => {
+                let (s, stack) = StackAffix::decode(s)?;
+                Ok((s, StackHyphenated::new(stack, None)))
+            }
+            Ok((s, fixed_left)) => {
+                let (s, left) = StackAffix::decode(follow_str(s, ":")?)?;
+                let (s, right) = StackAffix::decode(follow_str(s, ";-")?)?;
+                let right = Some(right);
+                Ok((
+                    s,
+                    StackHyphenated {
+                        left,
+                        right,
+                        fixed_left,
+                    },
+                ))
+            }
+        }
     }
 }
@@ -3,8 +3,8 @@ use std::{borrow::Cow, fmt};
 use crate::{
     analyzer::MorphAnalyzer,
     container::{
-        abc::*, paradigm::ParadigmId, Dictionary, HyphenAdverb, Initials, Lex, Score, Shaped,
-        Unknown,
+        abc::*, paradigm::ParadigmId, Abbreviation, Dictionary, HyphenAdverb, Initials, Lex, Score,
+        Shaped, Transliterated, Unknown, UserWord,
     },
     opencorpora::OpencorporaTagReg,
 };
@@ -13,11 +13,14 @@ use self::StackSource::*;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum StackSource {
+    Abbreviation(Abbreviation),
     Dictionary(Dictionary),
     HyphenAdverb(HyphenAdverb),
     Initials(Initials),
     Shaped(Shaped),
+    Transliterated(Transliterated),
     Unknown(Unknown),
+    UserWord(UserWord),
 }
 
 impl StackSource {
@@ -28,6 +31,13 @@ impl StackSource {
         source.into()
     }
 
+    pub fn as_abbreviation(&self) -> Option<&Abbreviation> {
+        match self {
+            Abbreviation(source) => Some(source),
+            _ => None,
+        }
+    }
+
     pub fn as_dictionary(&self) -> Option<&Dictionary> {
         match self {
             Dictionary(source) => Some(source),
@@ -56,6 +66,13 @@ impl StackSource {
         }
     }
 
+    pub fn as_transliterated(&self) -> Option<&Transliterated> {
+        match self {
+            Transliterated(source) => Some(source),
+            _ => None,
+        }
+    }
+
     pub fn as_unknown(&self) -> Option<&Unknown> {
         match self {
             Unknown(source) => Some(source),
@@ -63,21 +80,42 @@ impl StackSource {
         }
     }
 
+    pub fn as_user_word(&self) -> Option<&UserWord> {
+        match self {
+            UserWord(source) => Some(source),
+            _ => None,
+        }
+    }
+
     pub fn iter_lexeme<'s: 'i, 'm: 'i, 'i>(
         &'s self,
         morph: &'m MorphAnalyzer,
     ) -> Box<dyn Iterator<Item = Lex> + 'i> {
         match self {
+            Abbreviation(source) => Box::new(source.iter_lexeme(morph)),
             Dictionary(source) => Box::new(source.iter_lexeme(morph)),
             HyphenAdverb(source) => Box::new(source.iter_lexeme(morph)),
             Initials(source) => Box::new(source.iter_lexeme(morph)),
             Shaped(source) => Box::new(source.iter_lexeme(morph)),
+            Transliterated(source) => Box::new(source.iter_lexeme(morph)),
             Unknown(source) => Box::new(source.iter_lexeme(morph)),
+            UserWord(source) => Box::new(source.iter_lexeme(morph)),
+        }
+    }
+
+    /// Checks the ids of the source refer to existing entries of the analyzer,
+    /// which is not guaranteed for decoded sources.
+    pub fn is_valid(&self, morph: &MorphAnalyzer) -> bool {
+        match self {
+            Abbreviation(source) => morph.abbreviations.has_form(source.id(), source.idx()),
+            UserWord(source) => morph.lexicon.has_form(source.lexeme_id(), source.idx()),
+            _ => true,
         }
     }
 
     pub fn title_rus(&self) -> &'static str {
         match self {
+            Abbreviation(_) => "Сокращение",
             Dictionary(dict_source) => match dict_source.word_lower().is_known() {
                 true => "Словарное слово",
                 false => "Неизвестное слово",
@@ -85,11 +123,19 @@ impl StackSource {
             HyphenAdverb(_) => "Наречие с дефисом",
             Initials(_) => "Инициал",
             Shaped(_) => "Не слово",
+            Transliterated(_) => "Транслитерация",
             Unknown(_) => "Неизвестное слово",
+            UserWord(_) => "Пользовательское слово",
         }
     }
 }
 
+impl From<Abbreviation> for StackSource {
+    fn from(source: Abbreviation) -> Self {
+        Abbreviation(source)
+    }
+}
+
 impl From<Dictionary> for StackSource {
     fn from(source: Dictionary) -> Self {
         Dictionary(source)
@@ -114,120 +160,165 @@ impl From<Shaped> for StackSource {
     }
 }
 
+impl From<Transliterated> for StackSource {
+    fn from(source: Transliterated) -> Self {
+        Transliterated(source)
+    }
+}
+
 impl From<Unknown> for StackSource {
     fn from(source: Unknown) -> Self {
         Unknown(source)
     }
 }
 
+impl From<UserWord> for StackSource {
+    fn from(source: UserWord) -> Self {
+        UserWord(source)
+    }
+}
+
 impl Source for StackSource {
     fn score(&self) -> Score {
         match *self {
+            Abbreviation(ref source) => source.score(),
             Dictionary(ref source) => source.score(),
             HyphenAdverb(ref source) => source.score(),
             Initials(ref source) => source.score(),
             Shaped(ref source) => source.score(),
+            Transliterated(ref source) => source.score(),
             Unknown(ref source) => source.score(),
+            UserWord(ref source) => source.score(),
         }
     }
 
     fn is_lemma(&self) -> bool {
         match *self {
+            Abbreviation(ref source) => source.is_lemma(),
             Dictionary(ref source) => source.is_lemma(),
             HyphenAdverb(ref source) => source.is_lemma(),
             Initials(ref source) => source.is_lemma(),
             Shaped(ref source) => source.is_lemma(),
+            Transliterated(ref source) => source.is_lemma(),
             Unknown(ref source) => source.is_lemma(),
+            UserWord(ref source) => source.is_lemma(),
         }
     }
 
     fn is_known(&self) -> bool {
         match *self {
+            Abbreviation(ref source) => source.is_known(),
             Dictionary(ref source) => source.is_known(),
             HyphenAdverb(ref source) => source.is_known(),
             Initials(ref source) => source.is_known(),
             Shaped(ref source) => source.is_known(),
+            Transliterated(ref source) => source.is_known(),
             Unknown(ref source) => source.is_known(),
+            UserWord(ref source) => source.is_known(),
         }
     }
 
     fn get_word(&self) -> Cow<str> {
         match *self {
+            Abbreviation(ref source) => source.get_word(),
             Dictionary(ref source) => source.get_word(),
             HyphenAdverb(ref source) => source.get_word(),
             Initials(ref source) => source.get_word(),
             Shaped(ref source) => source.get_word(),
+            Transliterated(ref source) => source.get_word(),
             Unknown(ref source) => source.get_word(),
+            UserWord(ref source) => source.get_word(),
         }
     }
 
     fn get_normal_form(&self, morph: &MorphAnalyzer) -> Cow<str> {
         match *self {
+            Abbreviation(ref source) => source.get_normal_form(morph),
             Dictionary(ref source) => source.get_normal_form(morph),
             HyphenAdverb(ref source) => source.get_normal_form(morph),
             Initials(ref source) => source.get_normal_form(morph),
             Shaped(ref source) => source.get_normal_form(morph),
+            Transliterated(ref source) => source.get_normal_form(morph),
             Unknown(ref source) => source.get_normal_form(morph),
+            UserWord(ref source) => source.get_normal_form(morph),
         }
     }
 
     fn get_tag<'m>(&self, morph: &'m MorphAnalyzer) -> &'m OpencorporaTagReg {
         match *self {
+            Abbreviation(ref source) => source.get_tag(morph),
             Dictionary(ref source) => source.get_tag(morph),
             HyphenAdverb(ref source) => source.get_tag(morph),
             Initials(ref source) => source.get_tag(morph),
             Shaped(ref source) => source.get_tag(morph),
+            Transliterated(ref source) => source.get_tag(morph),
             Unknown(ref source) => source.get_tag(morph),
+            UserWord(ref source) => source.get_tag(morph),
         }
     }
 
     fn try_get_para_id(&self) -> Option<ParadigmId> {
         match *self {
+            Abbreviation(ref source) => source.try_get_para_id(),
             Dictionary(ref source) => source.try_get_para_id(),
             HyphenAdverb(ref source) => source.try_get_para_id(),
             Initials(ref source) => source.try_get_para_id(),
             Shaped(ref source) => source.try_get_para_id(),
+            Transliterated(ref source) => source.try_get_para_id(),
             Unknown(ref source) => source.try_get_para_id(),
+            UserWord(ref source) => source.try_get_para_id(),
         }
     }
 
     fn write_word<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
         match *self {
+            Abbreviation(ref source) => source.write_word(f),
             Dictionary(ref source) => source.write_word(f),
             HyphenAdverb(ref source) => source.write_word(f),
             Initials(ref source) => source.write_word(f),
             Shaped(ref source) => source.write_word(f),
+            Transliterated(ref source) => source.write_word(f),
             Unknown(ref source) => source.write_word(f),
+            UserWord(ref source) => source.write_word(f),
         }
     }
 
     fn write_normal_form<W: fmt::Write>(&self, f: &mut W, morph: &MorphAnalyzer) -> fmt::Result {
         match *self {
+            Abbreviation(ref source) => source.write_normal_form(f, morph),
             Dictionary(ref source) => source.write_normal_form(f, morph),
             HyphenAdverb(ref source) => source.write_normal_form(f, morph),
             Initials(ref source) => source.write_normal_form(f, morph),
             Shaped(ref source) => source.write_normal_form(f, morph),
+            Transliterated(ref source) => source.write_normal_form(f, morph),
             Unknown(ref source) => source.write_normal_form(f, morph),
+            UserWord(ref source) => source.write_normal_form(f, morph),
         }
     }
 
     fn get_lexeme(&self, morph: &MorphAnalyzer) -> Vec<Lex> {
         match *self {
+            Abbreviation(ref source) => source.get_lexeme(morph),
             Dictionary(ref source) => source.get_lexeme(morph),
             HyphenAdverb(ref source) => source.get_lexeme(morph),
             Initials(ref source) => source.get_lexeme(morph),
             Shaped(ref source) => source.get_lexeme(morph),
+            Transliterated(ref source) => source.get_lexeme(morph),
             Unknown(ref source) => source.get_lexeme(morph),
+            UserWord(ref source) => source.get_lexeme(morph),
         }
     }
 
     fn get_lemma(&self, morph: &MorphAnalyzer) -> Lex {
         match *self {
+            Abbreviation(ref source) => source.get_lemma(morph),
             Dictionary(ref source) => source.get_lemma(morph),
             HyphenAdverb(ref source) => source.get_lemma(morph),
             Initials(ref source) => source.get_lemma(morph),
             Shaped(ref source) => source.get_lemma(morph),
+            Transliterated(ref source) => source.get_lemma(morph),
             Unknown(ref source) => source.get_lemma(morph),
+            UserWord(ref source) => source.get_lemma(morph),
         }
     }
 }
@@ -235,11 +326,14 @@ impl Source for StackSource {
 impl MorphySerde for StackSource {
     fn encode<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
         match *self {
+            Abbreviation(ref source) => source.encode(f),
             Dictionary(ref source) => source.encode(f),
             HyphenAdverb(ref source) => source.encode(f),
             Initials(ref source) => source.encode(f),
             Shaped(ref source) => source.encode(f),
+            Transliterated(ref source) => source.encode(f),
             Unknown(ref source) => source.encode(f),
+            UserWord(ref source) => source.encode(f),
         }
     }
 
@@ -254,7 +348,16 @@ impl MorphySerde for StackSource {
                         Some(v) => v,
                         None => match try_decode::<Unknown>(s)? {
                             Some(v) => v,
-                            None => Err(DecodeError::UnknownPartType)?,
+                            None => match try_decode::<UserWord>(s)? {
+                                Some(v) => v,
+                                None => match try_decode::<Abbreviation>(s)? {
+                                    Some(v) => v,
+                                    None => match try_decode::<Transliterated>(s)? {
+                                        Some(v) => v,
+                                        None => Err(DecodeError::UnknownPartType)?,
+                                    },
+                                },
+                            },
                         },
                     },
                 },
@@ -0,0 +1,124 @@
+use std::{borrow::Cow, fmt};
+
+use crate::{
+    analyzer::MorphAnalyzer,
+    container::{
+        abc::*, decode::*, paradigm::ParadigmId, stack::StackSource, Dictionary, Lex, Score,
+    },
+    opencorpora::tag::OpencorporaTagReg,
+};
+
+/// Transliterations are guesses, so they rank below the same words spelled in Cyrillic.
+const SCORE_DECAY: f64 = 0.5;
+
+/// A dictionary word written in Latin letters: "privet" is "привет".
+///
+/// The word and the lexeme are the ones of the dictionary word;
+/// the original spelling is kept as `surface` in all the forms of the lexeme.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transliterated {
+    surface: String,
+    dict: Dictionary,
+}
+
+impl Transliterated {
+    pub fn new<S>(surface: S, dict: Dictionary) -> Self
+    where
+        S: Into<String>,
+    {
+        let surface = surface.into();
+        Transliterated { surface, dict }
+    }
+
+    /// The word as it was written: "privet".
+    pub fn surface(&self) -> &str {
+        &self.surface
+    }
+
+    pub fn dict(&self) -> &Dictionary {
+        &self.dict
+    }
+
+    pub fn iter_lexeme<'s: 'i, 'm: 'i, 'i>(
+        &'s self,
+        morph: &'m MorphAnalyzer,
+    ) -> impl Iterator<Item = Lex> + 'i {
+        self.dict.iter_forms(morph).map(move |dict| {
+            let container = Transliterated::new(self.surface.as_str(), dict);
+            Lex::from_stack(morph, StackSource::from(container))
+        })
+    }
+}
+
+impl Source for Transliterated {
+    fn score(&self) -> Score {
+        self.dict.score() * SCORE_DECAY
+    }
+
+    fn is_lemma(&self) -> bool {
+        self.dict.is_lemma()
+    }
+
+    fn is_known(&self) -> bool {
+        self.dict.is_known()
+    }
+
+    fn get_word(&self) -> Cow<str> {
+        self.dict.get_word()
+    }
+
+    fn get_normal_form(&self, morph: &MorphAnalyzer) -> Cow<str> {
+        self.dict.get_normal_form(morph)
+    }
+
+    fn get_tag<'m>(&self, morph: &'m MorphAnalyzer) -> &'m OpencorporaTagReg {
+        self.dict.get_tag(morph)
+    }
+
+    fn try_get_para_id(&self) -> Option<ParadigmId> {
+        self.dict.try_get_para_id()
+    }
+
+    fn write_word<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
+        self.dict.write_word(f)
+    }
+
+    fn write_normal_form<W: fmt::Write>(&self, f: &mut W, morph: &MorphAnalyzer) -> fmt::Result {
+        self.dict.write_normal_form(f, morph)
+    }
+
+    fn get_lexeme(&self, morph: &MorphAnalyzer) -> Vec<Lex> {
+        self.iter_lexeme(morph).collect()
+    }
+
+    fn get_lemma(&self, morph: &MorphAnalyzer) -> Lex {
+        self.iter_lexeme(morph).next().unwrap()
+    }
+}
+
+impl MorphySerde for Transliterated {
+    fn encode<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
+        write!(f, "tr:{},", self.surface)?;
+        self.dict.encode(f)
+    }
+
+    /**
+        ```
+        use rsmorphy::container::{Dictionary, Transliterated, WordStruct};
+        use rsmorphy::container::abc::*;
+
+        let dict = Dictionary::new(WordStruct::known("привет"), 0x2c_u16, 0_u16);
+        assert_eq!(
+            Transliterated::decode(r"tr:privet,d:привет,2c"),
+            Ok(("", Transliterated::new("privet", dict)))
+        );
+        assert_eq!(Transliterated::decode(r"tr:privet"), Err(DecodeError::UnexpectedEnd));
+        ```
+    */
+    fn decode(s: &str) -> Result<(&str, Self), DecodeError> {
+        let s = follow_str(s, "tr").map_err(|_| DecodeError::UnknownPartType)?;
+        let (s, surface) = take_str_until_char_is(follow_str(s, ":")?, ',')?;
+        let (s, dict) = Dictionary::decode(follow_str(s, ",")?)?;
+        Ok((s, Transliterated::new(surface, dict)))
+    }
+}
@@ -0,0 +1,154 @@
+use std::{borrow::Cow, fmt};
+
+use crate::{
+    analyzer::MorphAnalyzer,
+    container::{
+        abc::*,
+        decode::*,
+        paradigm::{ParadigmId, ParadigmIndex},
+        stack::StackSource,
+        Lex, Score, WordStruct,
+    },
+    opencorpora::tag::OpencorporaTagReg,
+};
+
+/// A form of a lexeme listed explicitly in the user lexicon.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserWord {
+    word_lower: WordStruct,
+    lexeme_id: usize,
+    idx: ParadigmIndex,
+}
+
+impl UserWord {
+    pub fn new<IDX>(word_lower: WordStruct, lexeme_id: usize, idx: IDX) -> Self
+    where
+        IDX: Into<ParadigmIndex>,
+    {
+        let idx = idx.into();
+        UserWord {
+            word_lower,
+            lexeme_id,
+            idx,
+        }
+    }
+
+    pub fn word_lower(&self) -> &WordStruct {
+        &self.word_lower
+    }
+
+    pub fn lexeme_id(&self) -> usize {
+        self.lexeme_id
+    }
+
+    pub fn idx(&self) -> ParadigmIndex {
+        self.idx
+    }
+
+    pub fn iter_lexeme<'s: 'i, 'm: 'i, 'i>(
+        &'s self,
+        morph: &'m MorphAnalyzer,
+    ) -> impl Iterator<Item = Lex> + 'i {
+        morph
+            .lexicon
+            .get_lexeme(self.lexeme_id)
+            .iter()
+            .enumerate()
+            .map(move |(idx, (word, _))| {
+                let word = WordStruct::known(word.as_str());
+                Lex::from_stack(
+                    morph,
+                    StackSource::from(UserWord::new(word, self.lexeme_id, idx)),
+                )
+            })
+    }
+}
+
+impl Source for UserWord {
+    fn score(&self) -> Score {
+        Score::Real(1.0)
+    }
+
+    fn is_lemma(&self) -> bool {
+        self.idx.is_first()
+    }
+
+    fn is_known(&self) -> bool {
+        self.word_lower.is_known()
+    }
+
+    fn get_word(&self) -> Cow<str> {
+        Cow::from(self.word_lower.word())
+    }
+
+    fn get_normal_form(&self, morph: &MorphAnalyzer) -> Cow<str> {
+        Cow::from(morph.lexicon.get_normal_form(self.lexeme_id).to_owned())
+    }
+
+    fn get_tag<'m>(&self, morph: &'m MorphAnalyzer) -> &'m OpencorporaTagReg {
+        morph.lexicon.get_tag(self.lexeme_id, self.idx)
+    }
+
+    fn try_get_para_id(&self) -> Option<ParadigmId> {
+        None
+    }
+
+    fn write_word<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
+        write!(f, "{}", self.word_lower.word())
+    }
+
+    fn write_normal_form<W: fmt::Write>(&self, f: &mut W, morph: &MorphAnalyzer) -> fmt::Result {
+        write!(f, "{}", morph.lexicon.get_normal_form(self.lexeme_id))
+    }
+
+    fn get_lexeme(&self, morph: &MorphAnalyzer) -> Vec<Lex> {
+        self.iter_lexeme(morph).collect()
+    }
+
+    fn get_lemma(&self, morph: &MorphAnalyzer) -> Lex {
+        self.iter_lexeme(morph).next().unwrap()
+    }
+}
+
+impl MorphySerde for UserWord {
+    fn encode<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
+        write!(f, "l:{},{:x}", self.word_lower.word(), self.lexeme_id)?;
+        if !self.idx.is_first() {
+            write!(f, ",{:x}", self.idx.value())?;
+        }
+        Ok(())
+    }
+
+    /**
+        ```
+        use rsmorphy::container::{UserWord, WordStruct};
+        use rsmorphy::container::abc::*;
+
+        assert_eq!(
+            UserWord::decode(r"l:маркетплейс,0"),
+            Ok(("", UserWord::new(WordStruct::known("маркетплейс"), 0, 0_u16)))
+        );
+
+        assert_eq!(
+            UserWord::decode(r"l:стартаперши,1a,1"),
+            Ok(("", UserWord::new(WordStruct::known("стартаперши"), 0x1a, 1_u16)))
+        );
+
+        assert_eq!(
+            UserWord::decode(r"l:стартаперши"),
+            Err(DecodeError::UnexpectedEnd)
+        );
+        ```
+    */
+    fn decode(s: &str) -> Result<(&str, Self), DecodeError> {
+        let s = follow_str(s, "l").map_err(|_| DecodeError::UnknownPartType)?;
+        let (s, word) = take_str_until_char_is(follow_str(s, ":")?, ',')?;
+        let (s, lexeme_id) = take_str_while_char(follow_str(s, ",")?, is_hex_digit)
+            .and_then(parse_hex_int::<usize>)?;
+        let (s, idx) = follow_str(s, ",")
+            .ok()
+            .map(|s| take_str_while_char(s, is_hex_digit).and_then(parse_hex_int::<u16>))
+            .unwrap_or_else(|| Ok((s, 0)))?;
+        Ok((s, UserWord::new(WordStruct::known(word), lexeme_id, idx)))
+    }
+}
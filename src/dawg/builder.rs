@@ -0,0 +1,215 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashSet},
+    io::{self, Write},
+};
+
+use byteorder::{LittleEndian, WriteBytesExt};
+
+use crate::dawg::units;
+
+const BLOCK_SIZE: usize = 256;
+/// The number of last blocks searched for free units.
+const NUM_EXTRA_BLOCKS: usize = 16;
+
+/**
+    Builds a DAWG with integer values (`IntCompletionDAWG` of the `DAWG` python package)
+    in the format read by `CompletionDawg`.
+
+    The keys are stored as a trie, which is a valid (though not minimal) DAWG.
+
+    ```
+    use rsmorphy::dawg::{builder::IntDawgBuilder, CompletionDawg, HH};
+
+    let mut builder = IntDawgBuilder::new();
+    builder.insert("ёж:NOUN", 42);
+    builder.insert("ежи:NOUN", 7);
+
+    let mut buf = Vec::new();
+    builder.write(&mut buf).unwrap();
+    let dawg = CompletionDawg::<HH>::from_reader(&mut buf.as_slice());
+    assert_eq!(dawg.find("ёж:NOUN"), Some(42));
+    assert_eq!(dawg.find("ежи:NOUN"), Some(7));
+    assert_eq!(dawg.find("еж:NOUN"), None);
+    ```
+*/
+#[derive(Debug, Default, Clone)]
+pub struct IntDawgBuilder {
+    entries: BTreeMap<Vec<u8>, u32>,
+}
+
+#[derive(Debug, Default)]
+struct Node {
+    children: BTreeMap<u8, usize>,
+    value: Option<u32>,
+}
+
+impl IntDawgBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Adds a key, replacing its previous value.
+    ///
+    /// Panics if the key is empty, contains zero bytes or the value doesn't fit into 31 bits.
+    pub fn insert(&mut self, key: &str, value: u32) {
+        assert!(
+            !key.is_empty() && !key.contains('\0'),
+            "invalid key {:?}",
+            key
+        );
+        assert!(
+            value & units::IS_LEAF_BIT == 0,
+            "value is too large: {}",
+            value
+        );
+        self.entries.insert(key.as_bytes().to_vec(), value);
+    }
+
+    /// Writes the dictionary and the guide; the output may be read by `CompletionDawg::from_reader`.
+    pub fn write<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        let (dict, guide) = self.build();
+        w.write_u32::<LittleEndian>(dict.len() as u32)?;
+        for &unit in &dict {
+            w.write_u32::<LittleEndian>(unit)?;
+        }
+        w.write_u32::<LittleEndian>(guide.len() as u32)?;
+        for &(child, sibling) in &guide {
+            w.write_u8(child)?;
+            w.write_u8(sibling)?;
+        }
+        Ok(())
+    }
+
+    fn trie(&self) -> Vec<Node> {
+        let mut nodes = vec![Node::default()];
+        for (key, &value) in &self.entries {
+            let mut id = 0;
+            for &label in key {
+                id = match nodes[id].children.get(&label) {
+                    Some(&child) => child,
+                    None => {
+                        nodes.push(Node::default());
+                        let child = nodes.len() - 1;
+                        nodes[id].children.insert(label, child);
+                        child
+                    }
+                };
+            }
+            nodes[id].value = Some(value);
+        }
+        nodes
+    }
+
+    /// Places the trie into a double array of units and builds the guide for it.
+    ///
+    /// Children of the unit `index` with labels `c` are placed at `index ^ offset ^ c`,
+    /// and the value at `index ^ offset`; every `index ^ offset` is used by one unit only.
+    fn build(&self) -> (Vec<u32>, Vec<(u8, u8)>) {
+        let nodes = self.trie();
+        let mut dict: Vec<u32> = Vec::new();
+        let mut guide: Vec<(u8, u8)> = Vec::new();
+        let mut used: Vec<bool> = Vec::new();
+        let mut used_bases: HashSet<usize> = HashSet::new();
+        // Free units of the last `NUM_EXTRA_BLOCKS` blocks
+        let mut free: BTreeSet<usize> = BTreeSet::new();
+
+        add_block(&mut dict, &mut guide, &mut used, &mut free);
+        used[0] = true;
+        free.remove(&0);
+
+        let mut stack = vec![(0, 0)];
+        while let Some((id, index)) = stack.pop() {
+            let node: &Node = &nodes[id];
+            if node.children.is_empty() && node.value.is_none() {
+                continue;
+            }
+            let labels: Vec<u8> = node
+                .value
+                .map(|_| 0)
+                .into_iter()
+                .chain(node.children.keys().cloned())
+                .collect();
+
+            let fits = |base: usize, used: &[bool]| {
+                let offset = index ^ base;
+                base != index
+                    && (offset < units::OFFSET_MAX as usize || offset % BLOCK_SIZE == 0)
+                    && !used_bases.contains(&base)
+                    && labels.iter().all(|&label| !used[base ^ label as usize])
+            };
+            let base = loop {
+                let found = free
+                    .iter()
+                    .map(|&unit| unit ^ labels[0] as usize)
+                    .find(|&base| fits(base, &used));
+                match found {
+                    Some(base) => break base,
+                    None => add_block(&mut dict, &mut guide, &mut used, &mut free),
+                }
+            };
+            used_bases.insert(base);
+
+            dict[index] |= encode_offset((index ^ base) as u32);
+            if let Some(value) = node.value {
+                dict[base] = units::IS_LEAF_BIT | value;
+                used[base] = true;
+                free.remove(&base);
+            }
+            let children: Vec<(u8, usize)> = node.children.iter().map(|(&l, &c)| (l, c)).collect();
+            guide[index].0 = children.first().map_or(0, |&(label, _)| label);
+            for (i, &(label, child)) in children.iter().enumerate() {
+                let child_index = base ^ label as usize;
+                used[child_index] = true;
+                free.remove(&child_index);
+                dict[child_index] = u32::from(label);
+                if nodes[child].value.is_some() {
+                    dict[child_index] |= units::HAS_LEAF_BIT;
+                }
+                guide[child_index].1 = children.get(i + 1).map_or(0, |&(label, _)| label);
+            }
+            stack.extend(
+                children
+                    .iter()
+                    .rev()
+                    .map(|&(label, child)| (child, base ^ label as usize)),
+            );
+        }
+        (dict, guide)
+    }
+}
+
+/// Appends a block of free units and forgets free units of the blocks out of the search window.
+fn add_block(
+    dict: &mut Vec<u32>,
+    guide: &mut Vec<(u8, u8)>,
+    used: &mut Vec<bool>,
+    free: &mut BTreeSet<usize>,
+) {
+    let start = dict.len();
+    dict.resize(start + BLOCK_SIZE, 0);
+    guide.resize(start + BLOCK_SIZE, (0, 0));
+    used.resize(start + BLOCK_SIZE, false);
+    free.extend(start..start + BLOCK_SIZE);
+    if let Some(start) = start.checked_sub(NUM_EXTRA_BLOCKS * BLOCK_SIZE) {
+        for index in start..start + BLOCK_SIZE {
+            free.remove(&index);
+        }
+    }
+}
+
+fn encode_offset(offset: u32) -> u32 {
+    if offset < units::OFFSET_MAX {
+        offset << 10
+    } else {
+        // Far offsets are multiples of the block size, so the lower bits can be dropped
+        ((offset >> 8) << 10) | units::EXTENSION_BIT
+    }
+}
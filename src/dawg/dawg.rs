@@ -1,4 +1,4 @@
-use std::{collections::BTreeMap, fs::File, io::Read, marker::PhantomData, path::Path};
+use std::{collections::BTreeMap, fs::File, io, io::Read, marker::PhantomData, path::Path};
 
 use base64;
 use flate2::read::GzDecoder;
@@ -17,16 +17,40 @@ impl Dawg {
     where
         P: AsRef<Path>,
     {
-        Self::from_reader(&mut GzDecoder::new(File::open(p).unwrap()))
+        Self::try_from_file(p).unwrap()
     }
 
     pub fn from_reader<T>(fp: &mut T) -> Self
     where
         T: Read,
     {
-        Dawg {
-            dict: Dictionary::from_reader(fp),
-        }
+        Self::try_from_reader(fp).unwrap()
+    }
+
+    pub fn try_from_file<P>(p: P) -> io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        Self::try_from_reader(&mut GzDecoder::new(File::open(p)?))
+    }
+
+    pub fn try_from_reader<T>(fp: &mut T) -> io::Result<Self>
+    where
+        T: Read,
+    {
+        Ok(Dawg {
+            dict: Dictionary::try_from_reader(fp)?,
+        })
+    }
+
+    /// Exact matching.
+    pub fn contains(&self, key: &str) -> bool {
+        self.dict.contains(key)
+    }
+
+    /// Checks if any key of this DAWG starts with the `prefix`.
+    pub fn has_keys_with_prefix(&self, prefix: &str) -> bool {
+        self.dict.follow_bytes(prefix, self.dict.root).is_some()
     }
 
     /// Returns a list with keys of this DAWG that are prefixes of the `key`.
@@ -70,18 +94,32 @@ where
     where
         P: AsRef<Path>,
     {
-        Self::from_reader(&mut GzDecoder::new(File::open(p).unwrap()))
+        Self::try_from_file(p).unwrap()
     }
 
     pub fn from_reader<T>(fp: &mut T) -> Self
     where
         T: Read,
     {
-        CompletionDawg {
-            dawg: Dawg::from_reader(fp),
-            guide: Guide::from_reader(fp),
+        Self::try_from_reader(fp).unwrap()
+    }
+
+    pub fn try_from_file<P>(p: P) -> io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        Self::try_from_reader(&mut GzDecoder::new(File::open(p)?))
+    }
+
+    pub fn try_from_reader<T>(fp: &mut T) -> io::Result<Self>
+    where
+        T: Read,
+    {
+        Ok(CompletionDawg {
+            dawg: Dawg::try_from_reader(fp)?,
+            guide: Guide::try_from_reader(fp)?,
             _phantom: PhantomData,
-        }
+        })
     }
 
     /// Returns a list of (key, value) tuples for all variants of `key`
@@ -173,6 +211,10 @@ where
         self.dawg.prefixes(key)
     }
 
+    pub fn has_keys_with_prefix(&self, prefix: &str) -> bool {
+        self.dawg.has_keys_with_prefix(prefix)
+    }
+
     pub fn find(&self, key: &str) -> Option<u32> {
         self.dawg.dict.find(key)
     }
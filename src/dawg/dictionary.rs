@@ -1,4 +1,4 @@
-use std::{fs::File, io::Read, path::Path};
+use std::{fs::File, io, io::Read, path::Path};
 
 use boolinator::Boolinator;
 use byteorder::{LittleEndian, ReadBytesExt};
@@ -17,7 +17,7 @@ pub struct Dictionary {
 impl Dictionary {
     /// Reads a dictionary from a file.
     pub fn from_file(p: &Path) -> Self {
-        Self::from_reader(&mut GzDecoder::new(File::open(p).unwrap()))
+        Self::try_from_file(p).unwrap()
     }
 
     /// Reads a dictionary from an input stream.
@@ -25,14 +25,28 @@ impl Dictionary {
     where
         T: Read,
     {
-        let size = reader.read_u32::<LittleEndian>().unwrap();
+        Self::try_from_reader(reader).unwrap()
+    }
+
+    /// Reads a dictionary from a file; unlike `from_file` returns I/O errors instead of panicking.
+    pub fn try_from_file(p: &Path) -> io::Result<Self> {
+        Self::try_from_reader(&mut GzDecoder::new(File::open(p)?))
+    }
+
+    /// Reads a dictionary from an input stream; unlike `from_reader` returns I/O errors
+    /// instead of panicking.
+    pub fn try_from_reader<T>(reader: &mut T) -> io::Result<Self>
+    where
+        T: Read,
+    {
+        let size = reader.read_u32::<LittleEndian>()?;
         let units = (0..size)
-            .map(|_| reader.read_u32::<LittleEndian>().unwrap())
-            .collect();
+            .map(|_| reader.read_u32::<LittleEndian>())
+            .collect::<io::Result<_>>()?;
 
         let root = 0;
 
-        Dictionary { root, units }
+        Ok(Dictionary { root, units })
     }
 
     /// Checks if a given index is related to the end of a key.
@@ -1,4 +1,4 @@
-use std::{fs::File, io::Read, path::Path};
+use std::{fs::File, io, io::Read, path::Path};
 
 use byteorder::{LittleEndian, ReadBytesExt};
 use flate2::read::GzDecoder;
@@ -19,7 +19,7 @@ pub struct Guide {
 impl Guide {
     /// Reads a guide from a file.
     pub fn from_file(p: &Path) -> Self {
-        Self::from_reader(&mut GzDecoder::new(File::open(p).unwrap()))
+        Self::try_from_file(p).unwrap()
     }
 
     /// Reads a guide from an input stream.
@@ -27,14 +27,32 @@ impl Guide {
     where
         T: Read,
     {
-        let base_size = fp.read_u32::<LittleEndian>().unwrap();
+        Self::try_from_reader(fp).unwrap()
+    }
+
+    /// Reads a guide from a file; unlike `from_file` returns I/O errors instead of panicking.
+    pub fn try_from_file(p: &Path) -> io::Result<Self> {
+        Self::try_from_reader(&mut GzDecoder::new(File::open(p)?))
+    }
+
+    /// Reads a guide from an input stream; unlike `from_reader` returns I/O errors
+    /// instead of panicking.
+    pub fn try_from_reader<T>(fp: &mut T) -> io::Result<Self>
+    where
+        T: Read,
+    {
+        let base_size = fp.read_u32::<LittleEndian>()?;
         let units: Vec<GuideEntry> = (0..base_size)
-            .map(|_| (fp.read_u8().unwrap(), fp.read_u8().unwrap()))
-            .map(|(child, sibling)| GuideEntry { child, sibling })
-            .collect();
+            .map(|_| {
+                Ok(GuideEntry {
+                    child: fp.read_u8()?,
+                    sibling: fp.read_u8()?,
+                })
+            })
+            .collect::<io::Result<_>>()?;
 
         let root = 0;
 
-        Guide { root, units }
+        Ok(Guide { root, units })
     }
 }
@@ -1,3 +1,4 @@
+pub mod builder;
 pub mod completer;
 pub mod dawg;
 pub mod dictionary;
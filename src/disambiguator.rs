@@ -0,0 +1,267 @@
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{self, BufWriter},
+    path::Path,
+};
+
+use flate2::{write::GzEncoder, Compression};
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{
+    analyzer::morph::by_score,
+    opencorpora::{error::GzReader, kind::case::Case, LoadError, OpencorporaTagReg},
+    prelude::*,
+};
+
+/// The state preceding the first token of a sequence.
+const START: &str = "";
+
+/// Used instead of zero P(t|w), so such parses are unlikely but still possible.
+const MIN_SCORE: f64 = 1e-6;
+
+/**
+    A tag-bigram hidden Markov model choosing parses of words by their neighbours.
+
+    States are coarse tags: the part of speech (or the first grammeme of tags without it)
+    with the case. Emissions are estimated from the scores of parses
+    (see `Estimator`), and the most probable sequence of parses
+    is found with the Viterbi algorithm.
+
+    ```
+    use rsmorphy::disambiguator::TagBigramModel;
+
+    let mut model = TagBigramModel::new();
+    model.add_sentence(&["NPRO,1per sing,nomn", "VERB,perf,intr plur,past,indc"]);
+    model.add_sentence(&["ADJF,Apro sing,masc,nomn", "NOUN,inan,masc sing,nomn"]);
+    assert!(model.transition("NPRO,nomn", "VERB") > model.transition("NPRO,nomn", "NOUN,nomn"));
+    ```
+*/
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TagBigramModel {
+    /// State -> the number of its occurrences
+    states: HashMap<String, u64>,
+    /// Previous state -> state -> the number of occurrences of the pair
+    transitions: HashMap<String, HashMap<String, u64>>,
+    /// Previous state -> the number of pairs starting with it
+    outgoing: HashMap<String, u64>,
+    /// The number of tokens
+    total: u64,
+}
+
+impl TagBigramModel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads the model saved by `save`.
+    pub fn load<P>(p: P) -> Result<Self, LoadError>
+    where
+        P: AsRef<Path>,
+    {
+        let path = p.as_ref();
+        let file = File::open(path).map_err(|e| LoadError::from_io(path, e))?;
+        serde_json::from_reader(GzReader::new(file)).map_err(|e| LoadError::from_json(path, e))
+    }
+
+    /// Saves the model as gzipped JSON.
+    pub fn save<P>(&self, p: P) -> io::Result<()>
+    where
+        P: AsRef<Path>,
+    {
+        let file = BufWriter::new(File::create(p)?);
+        let mut encoder = GzEncoder::new(file, Compression::default());
+        serde_json::to_writer(&mut encoder, self)?;
+        encoder.finish()?;
+        Ok(())
+    }
+
+    /// Trains the model on a sentence given as a sequence of OpenCorpora tags
+    /// (e.g. "NOUN,inan,femn sing,nomn").
+    pub fn add_sentence<I, T>(&mut self, tags: I)
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<str>,
+    {
+        let mut prev = START.to_owned();
+        for tag in tags {
+            let state = state(&OpencorporaTagReg::new(tag.as_ref()));
+            *self.states.entry(state.clone()).or_default() += 1;
+            *self.outgoing.entry(prev.clone()).or_default() += 1;
+            *self
+                .transitions
+                .entry(prev)
+                .or_default()
+                .entry(state.clone())
+                .or_default() += 1;
+            self.total += 1;
+            prev = state;
+        }
+    }
+
+    /// Trains the model on a sentence given as parses, taking the first parse of each word.
+    pub fn add_parsed_sentence(&mut self, morph: &MorphAnalyzer, sentence: &[ParseResult]) {
+        let tags = sentence
+            .iter()
+            .filter_map(|parses| parses.first())
+            .map(|parsed| parsed.lex.get_tag(morph).string.as_str());
+        self.add_sentence(tags);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.total == 0
+    }
+
+    /// P(state | prev) with the add-one smoothing; the start of a sequence is "".
+    pub fn transition(&self, prev: &str, state: &str) -> f64 {
+        let count = self
+            .transitions
+            .get(prev)
+            .and_then(|states| states.get(state))
+            .cloned()
+            .unwrap_or(0);
+        let outgoing = self.outgoing.get(prev).cloned().unwrap_or(0);
+        (count + 1) as f64 / (outgoing + self.states.len() as u64 + 1) as f64
+    }
+
+    /// P(state) with the add-one smoothing.
+    pub fn prior(&self, state: &str) -> f64 {
+        let count = self.states.get(state).cloned().unwrap_or(0);
+        (count + 1) as f64 / (self.total + self.states.len() as u64 + 1) as f64
+    }
+
+    /// Ranks parses of each word by the probability of the most probable path through them.
+    ///
+    /// The scores of parses are replaced with these probabilities normalized per word
+    /// and the parses are sorted by them, so the parse lying on the most probable path
+    /// of the sentence comes first. Empty results break the sequence.
+    pub fn disambiguate(&self, morph: &MorphAnalyzer, sentence: &mut [ParseResult]) {
+        for segment in sentence.split_mut(Vec::is_empty) {
+            let path_probs = self.path_probs(morph, segment);
+            for (parses, probs) in segment.iter_mut().zip(path_probs) {
+                let max = probs.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+                let total: f64 = probs.iter().map(|p| (p - max).exp()).sum();
+                for (parsed, p) in parses.iter_mut().zip(probs) {
+                    *parsed.score.value_ref_mut() = (p - max).exp() / total;
+                }
+                parses.sort_by(by_score);
+            }
+        }
+    }
+
+    /// Returns for each parse the log probability of the most probable path through it
+    /// (up to a constant common for the sentence).
+    fn path_probs(&self, morph: &MorphAnalyzer, sentence: &[ParseResult]) -> Vec<Vec<f64>> {
+        let states: Vec<Vec<String>> = sentence
+            .iter()
+            .map(|parses| {
+                parses
+                    .iter()
+                    .map(|parsed| state(parsed.lex.get_tag(morph)))
+                    .collect()
+            })
+            .collect();
+        // log P(w|t) up to a constant: log P(t|w) - log P(t)
+        let emission = |i: usize, j: usize| {
+            sentence[i][j].score.value().max(MIN_SCORE).ln() - self.prior(&states[i][j]).ln()
+        };
+        let transition =
+            |i: usize, k: usize, j: usize| self.transition(&states[i - 1][k], &states[i][j]).ln();
+
+        // The most probable paths from the start to each parse, including it
+        let mut forward: Vec<Vec<f64>> = Vec::with_capacity(sentence.len());
+        for i in 0..sentence.len() {
+            let probs = (0..states[i].len())
+                .map(|j| {
+                    let prev = if i == 0 {
+                        self.transition(START, &states[i][j]).ln()
+                    } else {
+                        (0..states[i - 1].len())
+                            .map(|k| forward[i - 1][k] + transition(i, k, j))
+                            .fold(f64::NEG_INFINITY, f64::max)
+                    };
+                    prev + emission(i, j)
+                })
+                .collect();
+            forward.push(probs);
+        }
+
+        // The most probable paths from each parse, excluding it, to the end
+        let mut backward: Vec<Vec<f64>> = vec![Vec::new(); sentence.len()];
+        for i in (0..sentence.len()).rev() {
+            backward[i] = (0..states[i].len())
+                .map(|j| {
+                    if i + 1 == sentence.len() {
+                        0.0
+                    } else {
+                        (0..states[i + 1].len())
+                            .map(|k| {
+                                transition(i + 1, j, k) + emission(i + 1, k) + backward[i + 1][k]
+                            })
+                            .fold(f64::NEG_INFINITY, f64::max)
+                    }
+                })
+                .collect();
+        }
+
+        forward
+            .into_iter()
+            .zip(backward)
+            .map(|(forward, backward)| forward.iter().zip(backward).map(|(f, b)| f + b).collect())
+            .collect()
+    }
+}
+
+/// Returns the coarse tag used as the state of the model: "NOUN,nomn", "VERB", "PNCT".
+fn state(tag: &OpencorporaTagReg) -> String {
+    let mut grammemes = tag.string.split([',', ' ']);
+    let pos = grammemes.next().unwrap_or_default();
+    match grammemes.find(|&grammeme| Case::try_from_str(grammeme).is_some()) {
+        Some(case) => format!("{},{}", pos, case),
+        None => pos.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::opencorpora::kind::pos::PartOfSpeach;
+
+    lazy_static::lazy_static! {
+        static ref RU: MorphAnalyzer = MorphAnalyzer::from_file(dict_ru::DICT_PATH);
+    }
+
+    #[test]
+    fn disambiguate() {
+        let mut model = TagBigramModel::new();
+        for _ in 0..10 {
+            model.add_sentence(&["NPRO,1per plur,nomn", "VERB,perf,intr plur,past,indc"]);
+        }
+        let path = std::env::temp_dir().join("rsmorphy-tag-model.json.gz");
+        model.save(&path).unwrap();
+        assert_eq!(TagBigramModel::load(&path).unwrap(), model);
+
+        let stali = |morph: &MorphAnalyzer| {
+            let tokens = morph.analyze_text("мы стали");
+            tokens[1].parses[0].lex.get_tag(morph).pos
+        };
+        let mut morph = RU.clone();
+        morph.tag_model = Some(Arc::new(model));
+        assert_eq!(stali(&morph), Some(PartOfSpeach::Verb));
+
+        // The scores agree with the chosen parses, so sorting by them keeps the choice
+        for token in morph.analyze_text("мы стали") {
+            let mut parses = token.parses.clone();
+            parses.sort_by(by_score);
+            assert_eq!(parses, token.parses);
+            let sum: f64 = parses.iter().map(|p| p.score.value()).sum();
+            assert!((sum - 1.0).abs() < 1e-9);
+        }
+
+        let mut sentence = vec![RU.parse("мы"), RU.parse("стали")];
+        RU.disambiguate(&mut sentence);
+        assert_eq!(sentence[1], RU.parse("стали"));
+    }
+}
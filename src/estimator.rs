@@ -1,8 +1,30 @@
-use std::cmp::Ordering;
+use std::{cmp::Ordering, fmt};
 
 use crate::{opencorpora::OpencorporaTagReg, prelude::*};
 
-#[derive(Debug, Clone, Copy)]
+/// Ranks parses of a word; see `MorphAnalyzer::estimator`.
+pub trait Estimator: fmt::Debug + Send + Sync {
+    /// Sets scores of the parses and sorts them from the most probable one.
+    fn apply_to_parses(
+        &self,
+        morph: &MorphAnalyzer,
+        word: &str,
+        word_lower: &str,
+        parses: &mut Vec<Parsed>,
+    );
+
+    /// Sorts the tags from the most probable one.
+    fn apply_to_tags<'m>(
+        &self,
+        morph: &'m MorphAnalyzer,
+        word: &str,
+        word_lower: &str,
+        tags: &mut Vec<&'m OpencorporaTagReg>,
+    );
+}
+
+/// Ranks parses by P(t|w) from the dictionary.
+#[derive(Debug, Default, Clone, Copy)]
 pub struct SingleTagProbabilityEstimator {}
 
 impl SingleTagProbabilityEstimator {
@@ -10,9 +32,11 @@ impl SingleTagProbabilityEstimator {
         let dawg_key = format!("{}:{}", word_lower, tag.string);
         f64::from(morph.dict.p_t_given_w.find(&dawg_key).unwrap_or(0)) / 1_000_000.0
     }
+}
 
-    pub fn apply_to_parses(
-        self,
+impl Estimator for SingleTagProbabilityEstimator {
+    fn apply_to_parses(
+        &self,
         morph: &MorphAnalyzer,
         _word: &str,
         word_lower: &str,
@@ -46,21 +70,73 @@ impl SingleTagProbabilityEstimator {
         }
     }
 
-    pub fn apply_to_tags(
-        self,
-        morph: &MorphAnalyzer,
+    fn apply_to_tags<'m>(
+        &self,
+        morph: &'m MorphAnalyzer,
         _word: &str,
         word_lower: &str,
-        tags: &mut Vec<OpencorporaTagReg>,
+        tags: &mut Vec<&'m OpencorporaTagReg>,
     ) {
         if tags.is_empty() {
             return;
         }
 
-        tags.sort_by(|t1: &OpencorporaTagReg, t2: &OpencorporaTagReg| {
+        tags.sort_by(|t1: &&OpencorporaTagReg, t2: &&OpencorporaTagReg| {
             self.prob(morph, word_lower, t2)
                 .partial_cmp(&self.prob(morph, word_lower, t1))
                 .unwrap_or(Ordering::Equal)
         });
     }
 }
+
+/// Keeps the order given by the units and only normalizes scores;
+/// useful for tests and for dictionaries without P(t|w).
+#[derive(Debug, Default, Clone, Copy)]
+pub struct UniformEstimator {}
+
+impl Estimator for UniformEstimator {
+    fn apply_to_parses(
+        &self,
+        _morph: &MorphAnalyzer,
+        _word: &str,
+        _word_lower: &str,
+        parses: &mut Vec<Parsed>,
+    ) {
+        let sum = parses.iter().map(|p: &Parsed| p.score.value()).sum::<f64>();
+        if sum > 0.0 {
+            for p in parses {
+                p.score = p.score * (1.0 / sum);
+            }
+        }
+    }
+
+    fn apply_to_tags<'m>(
+        &self,
+        _morph: &'m MorphAnalyzer,
+        _word: &str,
+        _word_lower: &str,
+        _tags: &mut Vec<&'m OpencorporaTagReg>,
+    ) {
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MorphAnalyzer;
+
+    lazy_static::lazy_static! {
+        static ref RU: MorphAnalyzer = MorphAnalyzer::from_file(dict_ru::DICT_PATH);
+    }
+
+    #[test]
+    fn estimator() {
+        let morph = MorphAnalyzer::builder()
+            .estimator(Box::new(UniformEstimator {}))
+            .build(RU.dict.clone());
+        let parses = morph.parse("стали");
+        assert_eq!(parses.len(), RU.parse("стали").len());
+        let sum: f64 = parses.iter().map(|p| p.score.value()).sum();
+        assert!((sum - 1.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,117 @@
+use std::collections::BTreeMap;
+
+/// A language of a dictionary.
+///
+/// Besides the dictionary data, it defines language specific defaults of the analyzer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Language {
+    Russian,
+    Ukrainian,
+}
+
+impl Language {
+    /// Returns the language with the code used in the dictionary meta and in encoded ids.
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code {
+            "ru" => Some(Language::Russian),
+            "uk" => Some(Language::Ukrainian),
+            _ => None,
+        }
+    }
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::Russian => "ru",
+            Language::Ukrainian => "uk",
+        }
+    }
+
+    /// Letters which may be written instead of others (e.g. "е" instead of "ё")
+    /// when the dictionary doesn't specify them.
+    pub fn char_substitutes(self) -> BTreeMap<String, String> {
+        match self {
+            Language::Russian => maplit::btreemap! {"е".into() => "ё".into()},
+            Language::Ukrainian => maplit::btreemap! {
+                "г".into() => "ґ".into(),
+                "’".into() => "'".into(),
+                "ʼ".into() => "'".into(),
+                "‘".into() => "'".into(),
+                "`".into() => "'".into(),
+            },
+        }
+    }
+
+    /// Particles which may be attached to a word with a hyphen (смотри-ка).
+    pub fn particles_after_hyphen(self) -> &'static [&'static str] {
+        match self {
+            Language::Russian => &["-то", "-ка", "-таки", "-де", "-тко", "-тка", "-с", "-ста"],
+            Language::Ukrainian => &["-но", "-таки", "-бо", "-от"],
+        }
+    }
+
+    /// The built-in list of abbreviations (see `Abbreviations::parse`).
+    pub fn abbreviations(self) -> &'static str {
+        match self {
+            Language::Russian => crate::abbreviations::RUSSIAN,
+            Language::Ukrainian => "",
+        }
+    }
+
+    /// Upper case letters which may be initials.
+    pub fn initials_letters(self) -> &'static str {
+        match self {
+            Language::Russian => "АБВГДЕЁЖЗИЙКЛМНОПРСТУФХЦЧШЩЭЮЯ",
+            Language::Ukrainian => "АБВГҐДЕЄЖЗИІЇЙКЛМНОПРСТУФХЦЧШЩЮЯ",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        container::{abc::*, Lex},
+        MorphAnalyzer,
+    };
+
+    lazy_static::lazy_static! {
+        static ref RU: MorphAnalyzer = MorphAnalyzer::from_file(dict_ru::DICT_PATH);
+    }
+
+    #[test]
+    fn language() {
+        assert_eq!(RU.language(), Language::Russian);
+        let lex = &RU.parse("минимальный")[0].lex;
+        assert!(lex.encoded().starts_with("ru:"));
+        assert_eq!(Lex::from_id(&RU, lex.encoded()).as_ref(), Ok(lex));
+
+        let mut uk = lex.clone();
+        uk.language = Language::Ukrainian;
+        assert_eq!(
+            Lex::from_id(&RU, uk.encoded()),
+            Err(DecodeError::DoesntMatch)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "dict_uk")]
+    fn load_uk() {
+        use crate::opencorpora::{kind::case::Case, GrammemeSet};
+
+        let uk = MorphAnalyzer::from_file(dict_uk::DICT_PATH);
+        assert_eq!(uk.language(), Language::Ukrainian);
+
+        let parsed = uk.parse("книгою");
+        let lex = &parsed
+            .iter()
+            .find(|p| p.lex.get_tag(&uk).case == Some(Case::Ablt))
+            .expect("no instrumental parse")
+            .lex;
+        assert!(lex.encoded().starts_with("uk:"));
+        assert_eq!(Lex::from_id(&uk, lex.encoded()).as_ref(), Ok(lex));
+        assert_eq!(lex.get_normal_form(&uk), "книга");
+
+        let gent = lex.inflect(&uk, &GrammemeSet::new("plur,gent")).unwrap();
+        assert_eq!(gent.get_word(), "книг");
+    }
+}
@@ -11,13 +11,19 @@
 #[macro_use]
 pub mod macros;
 
+pub mod abbreviations;
 pub mod analyzer;
 pub mod container;
 pub mod dawg;
+pub mod disambiguator;
 pub mod estimator;
+pub mod language;
+pub mod names;
+pub mod numbers;
 pub mod opencorpora;
 pub mod release;
 pub mod shapes;
+pub mod tokenize;
 pub mod util;
 
 pub mod prelude;
@@ -28,5 +34,6 @@ pub use crate::{
         abc::{MorphySerde, Source},
         Lex, ParseResult, Parsed, Score,
     },
+    language::Language,
     opencorpora::{Grammeme, GrammemeSet},
 };
@@ -0,0 +1,356 @@
+use std::fmt;
+
+use maplit::hashset;
+
+use crate::{
+    opencorpora::{
+        kind::{case::Case, gender::Gender, number::Number, pos::PartOfSpeach},
+        OpencorporaTagReg,
+    },
+    prelude::*,
+    shapes::restore_capitalization,
+};
+
+/// A part of a full name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NameKind {
+    /// фамилия
+    Surname,
+    /// имя
+    FirstName,
+    /// отчество
+    Patronymic,
+    /// Initials of the first name and the patronymic: "А.", "А.С."
+    Initials,
+}
+
+impl NameKind {
+    /// The grammeme of dictionary words of the kind.
+    fn grammeme(self) -> Option<Grammeme> {
+        match self {
+            NameKind::Surname => Some(Grammeme::new("Surn")),
+            NameKind::FirstName => Some(Grammeme::new("Name")),
+            NameKind::Patronymic => Some(Grammeme::new("Patr")),
+            NameKind::Initials => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamePart {
+    pub text: String,
+    pub kind: NameKind,
+}
+
+/// Kinds of the words (not initials) of a name, in order.
+const KINDS: [NameKind; 3] = [NameKind::Surname, NameKind::FirstName, NameKind::Patronymic];
+
+/// Usual orders of the words of a name.
+const ORDERS: &[&[NameKind]] = &[
+    &[NameKind::Surname, NameKind::FirstName, NameKind::Patronymic],
+    &[NameKind::FirstName, NameKind::Patronymic, NameKind::Surname],
+    &[NameKind::Surname, NameKind::FirstName],
+    &[NameKind::FirstName, NameKind::Surname],
+    &[NameKind::FirstName, NameKind::Patronymic],
+];
+
+regex!(PART_RE, r"(?:\p{Lu}\.)+|[\p{L}\p{M}'’-]+");
+regex!(MASC_PATRONYMIC_RE, r"ич$");
+regex!(FEMN_PATRONYMIC_RE, r"(?:овна|евна|ична)$");
+regex!(MASC_SURNAME_RE, r"(?:ов|ев|ёв|ин|ын|ский|цкий|ской|цкой)$");
+regex!(FEMN_SURNAME_RE, r"(?:ова|ева|ёва|ина|ына|ская|цкая)$");
+// Surnames which are not declined for any gender: Дурново, Шевченко, Живаго, Черных
+regex!(INDECLINABLE_SURNAME_RE, r"(?:[еёиоуыэю]|ых|их)$");
+// Surnames which are not declined for women: Кац, Шмидт, Гоголь
+regex!(CONSONANT_END_RE, r"[бвгджзйклмнпрстфхцчшщь]$");
+
+/**
+    A full name (ФИО) of a person.
+
+    The parts may go in any order ("Иванов Пётр Сергеевич", "Анна Петрова"),
+    and the first name and the patronymic may be replaced by initials ("Иванов П.С.").
+    Kinds of the parts are guessed by their dictionary tags, endings and the usual orders,
+    and the gender of the person by the patronymic, the first name and the surname.
+
+    ```no_run
+    use rsmorphy::{names::FullName, opencorpora::kind::case::Case, MorphAnalyzer};
+
+    let morph = MorphAnalyzer::from_file(dict_ru::DICT_PATH);
+    let name = FullName::parse(&morph, "Иванов Пётр Сергеевич").unwrap();
+    assert_eq!(name.inflect(&morph, Case::Datv).to_string(), "Иванову Петру Сергеевичу");
+    ```
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FullName {
+    /// Parts in the order of the text
+    pub parts: Vec<NamePart>,
+    /// The gender of the person, if it may be guessed
+    pub gender: Option<Gender>,
+}
+
+impl FullName {
+    /// Parses a name in nominative; returns `None` if there are no words
+    /// or more than three of them (not counting initials).
+    pub fn parse(morph: &MorphAnalyzer, text: &str) -> Option<Self> {
+        let texts: Vec<&str> = PART_RE.find_iter(text).map(|m| m.as_str()).collect();
+        let is_initials = |text: &str| text.ends_with('.') || text.chars().count() == 1;
+        let words: Vec<&str> = texts.iter().cloned().filter(|t| !is_initials(t)).collect();
+        if texts.is_empty() || words.len() > KINDS.len() {
+            return None;
+        }
+
+        let parses: Vec<ParseResult> = words.iter().map(|word| morph.parse(word)).collect();
+        let with_initials = words.len() < texts.len();
+        let kinds = assignments(words.len())
+            .into_iter()
+            .map(|kinds| {
+                let mut score: f64 = kinds
+                    .iter()
+                    .zip(&words)
+                    .zip(&parses)
+                    .map(|((&kind, word), parses)| kind_score(morph, word, parses, kind))
+                    .sum();
+                if !with_initials && ORDERS.contains(&kinds.as_slice()) {
+                    score += 0.5;
+                }
+                (kinds, score)
+            })
+            .fold((Vec::new(), f64::NEG_INFINITY), |a, b| {
+                if b.1 > a.1 {
+                    b
+                } else {
+                    a
+                }
+            })
+            .0;
+
+        let mut kinds = kinds.into_iter();
+        let parts: Vec<NamePart> = texts
+            .iter()
+            .map(|&text| NamePart {
+                text: text.to_owned(),
+                kind: match is_initials(text) {
+                    true => NameKind::Initials,
+                    false => kinds.next().unwrap_or(NameKind::Surname),
+                },
+            })
+            .collect();
+        let gender = detect_gender(morph, &parts);
+        Some(FullName { parts, gender })
+    }
+
+    /// Returns the text of the first part of the kind.
+    pub fn get(&self, kind: NameKind) -> Option<&str> {
+        self.parts
+            .iter()
+            .find(|part| part.kind == kind)
+            .map(|part| part.text.as_str())
+    }
+
+    /// Declines all parts of the name; initials, indeclinable surnames
+    /// and words unknown to the analyzer are kept as they are.
+    pub fn inflect(&self, morph: &MorphAnalyzer, case: Case) -> FullName {
+        FullName {
+            parts: self
+                .parts
+                .iter()
+                .map(|part| NamePart {
+                    text: self.inflect_part(morph, part, case),
+                    kind: part.kind,
+                })
+                .collect(),
+            gender: self.gender,
+        }
+    }
+
+    fn inflect_part(&self, morph: &MorphAnalyzer, part: &NamePart, case: Case) -> String {
+        let grammeme = match part.kind.grammeme() {
+            Some(grammeme) => grammeme,
+            None => return part.text.clone(),
+        };
+        let word = part.text.to_lowercase();
+        if part.kind == NameKind::Surname
+            && (INDECLINABLE_SURNAME_RE.is_match(&word)
+                || self.gender == Some(Gender::Femn) && CONSONANT_END_RE.is_match(&word))
+        {
+            return part.text.clone();
+        }
+
+        let parses = morph.parse(&word);
+        let agrees = |tag: &OpencorporaTagReg| {
+            tag.case == Some(Case::Nomn)
+                && (self.gender.is_none() || tag.gender.is_none() || tag.gender == self.gender)
+        };
+        let lex = parses
+            .iter()
+            .map(|parsed| &parsed.lex)
+            .find(|lex| {
+                let tag = lex.get_tag(morph);
+                tag.grammemes.set.contains(&grammeme) && agrees(tag)
+            })
+            .or_else(|| {
+                parses.iter().map(|parsed| &parsed.lex).find(|lex| {
+                    let tag = lex.get_tag(morph);
+                    tag.pos == Some(PartOfSpeach::Noun) && agrees(tag)
+                })
+            });
+        let required = GrammemeSet {
+            set: hashset! { case.to_grammeme(), Number::Sing.to_grammeme() },
+        };
+        match lex.and_then(|lex| lex.inflect(morph, &required)) {
+            Some(form) => restore_capitalization(&form.get_word(), &part.text),
+            None => part.text.clone(),
+        }
+    }
+}
+
+impl fmt::Display for FullName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, part) in self.parts.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}", part.text)?;
+        }
+        Ok(())
+    }
+}
+
+/// All ways to assign distinct kinds to `n` words.
+fn assignments(n: usize) -> Vec<Vec<NameKind>> {
+    let mut result = vec![Vec::new()];
+    for _ in 0..n {
+        let mut next = Vec::new();
+        for prefix in &result {
+            for &kind in &KINDS {
+                if !prefix.contains(&kind) {
+                    let mut kinds = prefix.clone();
+                    kinds.push(kind);
+                    next.push(kinds);
+                }
+            }
+        }
+        result = next;
+    }
+    result
+}
+
+/// How likely the word is a part of the kind; words unknown to the dictionary
+/// are most likely surnames.
+fn kind_score(morph: &MorphAnalyzer, word: &str, parses: &[Parsed], kind: NameKind) -> f64 {
+    let word = word.to_lowercase();
+    let in_dict = match kind.grammeme() {
+        Some(grammeme) => parses
+            .iter()
+            .any(|parsed| parsed.lex.get_tag(morph).grammemes.set.contains(&grammeme)),
+        None => false,
+    };
+    let by_ending = match kind {
+        NameKind::Patronymic
+            if MASC_PATRONYMIC_RE.is_match(&word) || FEMN_PATRONYMIC_RE.is_match(&word) =>
+        {
+            1.0
+        }
+        NameKind::Surname if MASC_SURNAME_RE.is_match(&word) || FEMN_SURNAME_RE.is_match(&word) => {
+            0.5
+        }
+        NameKind::Surname => 0.1,
+        _ => 0.0,
+    };
+    if in_dict {
+        1.0 + by_ending
+    } else {
+        by_ending
+    }
+}
+
+/// Guesses the gender by the patronymic, then by the first name, then by the surname.
+fn detect_gender(morph: &MorphAnalyzer, parts: &[NamePart]) -> Option<Gender> {
+    let word = |kind: NameKind| {
+        parts
+            .iter()
+            .find(|part| part.kind == kind)
+            .map(|part| part.text.to_lowercase())
+    };
+    // The only gender of the nominative dictionary words of the kind
+    let dict_gender = |word: &str, kind: NameKind| {
+        let grammeme = kind.grammeme()?;
+        let mut genders = morph
+            .tag(word)
+            .into_iter()
+            .filter_map(|tag| match tag.case {
+                Some(Case::Nomn) if tag.grammemes.set.contains(&grammeme) => Some(tag.gender),
+                _ => None,
+            });
+        let first = genders.next()??;
+        match genders.all(|gender| gender == Some(first)) {
+            true => Some(first),
+            false => None,
+        }
+    };
+
+    if let Some(word) = word(NameKind::Patronymic) {
+        if MASC_PATRONYMIC_RE.is_match(&word) {
+            return Some(Gender::Masc);
+        }
+        if FEMN_PATRONYMIC_RE.is_match(&word) {
+            return Some(Gender::Femn);
+        }
+    }
+    if let Some(gender) =
+        word(NameKind::FirstName).and_then(|word| dict_gender(&word, NameKind::FirstName))
+    {
+        return Some(gender);
+    }
+    let word = word(NameKind::Surname)?;
+    if FEMN_SURNAME_RE.is_match(&word) {
+        Some(Gender::Femn)
+    } else if MASC_SURNAME_RE.is_match(&word) {
+        Some(Gender::Masc)
+    } else {
+        dict_gender(&word, NameKind::Surname)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::opencorpora::kind::case::Case;
+
+    lazy_static::lazy_static! {
+        static ref RU: MorphAnalyzer = MorphAnalyzer::from_file(dict_ru::DICT_PATH);
+    }
+
+    #[test]
+    fn full_names() {
+        let inflect = |name: &str, case: Case| -> String {
+            FullName::parse(&RU, name)
+                .unwrap()
+                .inflect(&RU, case)
+                .to_string()
+        };
+        let name = FullName::parse(&RU, "Иванов Пётр Сергеевич").unwrap();
+        assert_eq!(name.gender, Some(Gender::Masc));
+        assert_eq!(name.get(NameKind::Surname), Some("Иванов"));
+        assert_eq!(name.get(NameKind::Patronymic), Some("Сергеевич"));
+        assert_eq!(
+            name.inflect(&RU, Case::Datv).to_string(),
+            "Иванову Петру Сергеевичу"
+        );
+
+        let name = FullName::parse(&RU, "Анна Петрова").unwrap();
+        assert_eq!(name.gender, Some(Gender::Femn));
+        assert_eq!(name.get(NameKind::FirstName), Some("Анна"));
+        assert_eq!(inflect("Анна Петрова", Case::Gent), "Анны Петровой");
+        assert_eq!(inflect("Петрова Анна", Case::Ablt), "Петровой Анной");
+        assert_eq!(inflect("Иванов П.С.", Case::Ablt), "Ивановым П.С.");
+        assert_eq!(inflect("А. С. Пушкин", Case::Loct), "А. С. Пушкине");
+        assert_eq!(
+            inflect("Шевченко Тарас Григорьевич", Case::Datv),
+            "Шевченко Тарасу Григорьевичу"
+        );
+        assert_eq!(inflect("Дурново Ольга", Case::Gent), "Дурново Ольги");
+        assert_eq!(inflect("Кац Анна Львовна", Case::Datv), "Кац Анне Львовне");
+        assert!(FullName::parse(&RU, "  ").is_none());
+    }
+}
@@ -0,0 +1,774 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Mutex, MutexGuard, PoisonError},
+};
+
+use maplit::hashset;
+
+use crate::{
+    opencorpora::{
+        kind::{animacy::Animacy, case::Case, gender::Gender, number::Number, pos::PartOfSpeach},
+        OpencorporaTagReg,
+    },
+    prelude::*,
+};
+
+const UNITS: [&str; 20] = [
+    "",
+    "один",
+    "два",
+    "три",
+    "четыре",
+    "пять",
+    "шесть",
+    "семь",
+    "восемь",
+    "девять",
+    "десять",
+    "одиннадцать",
+    "двенадцать",
+    "тринадцать",
+    "четырнадцать",
+    "пятнадцать",
+    "шестнадцать",
+    "семнадцать",
+    "восемнадцать",
+    "девятнадцать",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "",
+    "двадцать",
+    "тридцать",
+    "сорок",
+    "пятьдесят",
+    "шестьдесят",
+    "семьдесят",
+    "восемьдесят",
+    "девяносто",
+];
+
+const HUNDREDS: [&str; 10] = [
+    "",
+    "сто",
+    "двести",
+    "триста",
+    "четыреста",
+    "пятьсот",
+    "шестьсот",
+    "семьсот",
+    "восемьсот",
+    "девятьсот",
+];
+
+const ORDINAL_UNITS: [&str; 20] = [
+    "",
+    "первый",
+    "второй",
+    "третий",
+    "четвёртый",
+    "пятый",
+    "шестой",
+    "седьмой",
+    "восьмой",
+    "девятый",
+    "десятый",
+    "одиннадцатый",
+    "двенадцатый",
+    "тринадцатый",
+    "четырнадцатый",
+    "пятнадцатый",
+    "шестнадцатый",
+    "семнадцатый",
+    "восемнадцатый",
+    "девятнадцатый",
+];
+
+const ORDINAL_TENS: [&str; 10] = [
+    "",
+    "",
+    "двадцатый",
+    "тридцатый",
+    "сороковой",
+    "пятидесятый",
+    "шестидесятый",
+    "семидесятый",
+    "восьмидесятый",
+    "девяностый",
+];
+
+/// Nouns naming powers of 1000, enough for any `i128`.
+const SCALES: [&str; 13] = [
+    "",
+    "тысяча",
+    "миллион",
+    "миллиард",
+    "триллион",
+    "квадриллион",
+    "квинтиллион",
+    "секстиллион",
+    "септиллион",
+    "октиллион",
+    "нониллион",
+    "дециллион",
+    "ундециллион",
+];
+
+/// The form a number is spelled in; the gender and the animacy are of the counted noun.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NumeralForm {
+    pub case: Case,
+    pub gender: Gender,
+    pub animacy: Animacy,
+}
+
+impl NumeralForm {
+    pub fn new(case: Case, gender: Gender, animacy: Animacy) -> Self {
+        NumeralForm {
+            case,
+            gender,
+            animacy,
+        }
+    }
+}
+
+impl Default for NumeralForm {
+    fn default() -> Self {
+        NumeralForm::new(Case::Nomn, Gender::Masc, Animacy::Inan)
+    }
+}
+
+/**
+    Spells the number out as a cardinal numeral: "две тысячи двадцать три".
+
+    Words are taken from the dictionary and inflected with `Lex::inflect`;
+    nouns of thousands agree with the numbers before them ("двум тысячам").
+    In accusative, the animacy changes simple numerals only: "двух студентов",
+    but "двадцать два студента".
+
+    ```no_run
+    use rsmorphy::{numbers::{cardinal, NumeralForm}, opencorpora::kind::case::Case, MorphAnalyzer};
+    use rsmorphy::opencorpora::kind::{animacy::Animacy, gender::Gender};
+
+    let morph = MorphAnalyzer::from_file(dict_ru::DICT_PATH);
+    let loct = NumeralForm::new(Case::Loct, Gender::Masc, Animacy::Inan);
+    assert_eq!(cardinal(&morph, 23, loct), "двадцати трёх");
+    ```
+*/
+pub fn cardinal(morph: &MorphAnalyzer, num: i128, form: NumeralForm) -> String {
+    let mut words = Vec::new();
+    if num < 0 {
+        words.push("минус".to_owned());
+    }
+    let abs = num.unsigned_abs();
+    if abs == 0 {
+        words.push(inflect(
+            morph,
+            "ноль",
+            PartOfSpeach::Noun,
+            &noun_grammemes(form.case),
+        ));
+        return words.join(" ");
+    }
+
+    let triples = triples(abs);
+    for (scale, &triple) in triples.iter().enumerate().rev().filter(|&(_, &t)| t != 0) {
+        if scale == 0 {
+            let animacy = match abs % 100 {
+                n if abs < 5 || n % 10 == 1 && n != 11 => form.animacy,
+                _ => Animacy::Inan,
+            };
+            let form = NumeralForm { animacy, ..form };
+            words.extend(triple_words(morph, triple, form));
+        } else {
+            words.extend(scale_words(morph, scale, triple, form.case));
+        }
+    }
+    words.join(" ")
+}
+
+/**
+    Spells the number out as an ordinal numeral: "две тысячи двадцать третий".
+
+    Only the last word is ordinal; round thousands and millions make a compound word:
+    "двухтысячный", "двадцатиоднотысячный".
+
+    ```no_run
+    use rsmorphy::{numbers::{ordinal, NumeralForm}, opencorpora::kind::case::Case, MorphAnalyzer};
+    use rsmorphy::opencorpora::kind::{animacy::Animacy, gender::Gender};
+
+    let morph = MorphAnalyzer::from_file(dict_ru::DICT_PATH);
+    let femn = NumeralForm::new(Case::Nomn, Gender::Femn, Animacy::Inan);
+    assert_eq!(ordinal(&morph, 2000, femn), "двухтысячная");
+    ```
+*/
+pub fn ordinal(morph: &MorphAnalyzer, num: i128, form: NumeralForm) -> String {
+    let mut words = Vec::new();
+    if num < 0 {
+        words.push("минус".to_owned());
+    }
+    let abs = num.unsigned_abs();
+    let required = ordinal_grammemes(form);
+    if abs == 0 {
+        words.push(inflect(morph, "нулевой", PartOfSpeach::Adjf, &required));
+        return words.join(" ");
+    }
+
+    let triples = triples(abs);
+    let last = triples.iter().position(|&t| t != 0).unwrap_or(0);
+    for (scale, &triple) in triples.iter().enumerate().skip(last + 1).rev() {
+        if triple != 0 {
+            words.extend(scale_words(morph, scale, triple, Case::Nomn));
+        }
+    }
+
+    let triple = triples[last];
+    if last == 0 {
+        let components = components(triple);
+        let (&ordinal, cardinals) = components.split_last().unwrap_or((&0, &[]));
+        for &component in cardinals {
+            let required = numeral_grammemes(NumeralForm::default());
+            words.push(inflect(
+                morph,
+                cardinal_lemma(component),
+                PartOfSpeach::Numr,
+                &required,
+            ));
+        }
+        let word = if ordinal >= 100 {
+            let prefix = match ordinal / 100 {
+                1 => String::new(),
+                n => compound_prefix(morph, n),
+            };
+            prefix + &inflect(morph, "сотый", PartOfSpeach::Adjf, &required)
+        } else if ordinal >= 20 {
+            inflect(
+                morph,
+                ORDINAL_TENS[ordinal as usize / 10],
+                PartOfSpeach::Adjf,
+                &required,
+            )
+        } else {
+            inflect(
+                morph,
+                ORDINAL_UNITS[ordinal as usize],
+                PartOfSpeach::Adjf,
+                &required,
+            )
+        };
+        words.push(word);
+    } else {
+        let prefix = match triple {
+            1 => String::new(),
+            n => compound_prefix(morph, n),
+        };
+        let lemma = format!("{}ный", SCALES[last].trim_end_matches('а'));
+        words.push(prefix + &inflect(morph, &lemma, PartOfSpeach::Adjf, &required));
+    }
+    words.join(" ")
+}
+
+/// Splits the number into groups of three digits, the lowest first.
+fn triples(mut num: u128) -> Vec<u16> {
+    let mut result = Vec::new();
+    while num > 0 {
+        result.push((num % 1000) as u16);
+        num /= 1000;
+    }
+    result
+}
+
+/// Splits 1..999 into numbers named by a single word: 123 -> [100, 20, 3], 112 -> [100, 12].
+fn components(triple: u16) -> Vec<u16> {
+    let (hundreds, rest) = (triple / 100, triple % 100);
+    let mut result = Vec::new();
+    if hundreds > 0 {
+        result.push(hundreds * 100);
+    }
+    if (10..20).contains(&rest) {
+        result.push(rest);
+    } else {
+        if rest >= 20 {
+            result.push(rest / 10 * 10);
+        }
+        if rest % 10 > 0 {
+            result.push(rest % 10);
+        }
+    }
+    result
+}
+
+fn cardinal_lemma(component: u16) -> &'static str {
+    match component {
+        n if n >= 100 => HUNDREDS[n as usize / 100],
+        n if n >= 20 => TENS[n as usize / 10],
+        n => UNITS[n as usize],
+    }
+}
+
+/// Cardinal words of 1..999 counting a noun of the form.
+fn triple_words(morph: &MorphAnalyzer, triple: u16, form: NumeralForm) -> Vec<String> {
+    components(triple)
+        .into_iter()
+        .map(|component| {
+            // "два" has no neuter forms of its own
+            let form = match (component, form.gender) {
+                (2, Gender::Neut) => NumeralForm {
+                    gender: Gender::Masc,
+                    ..form
+                },
+                _ => form,
+            };
+            let required = numeral_grammemes(form);
+            inflect(
+                morph,
+                cardinal_lemma(component),
+                PartOfSpeach::Numr,
+                &required,
+            )
+        })
+        .collect()
+}
+
+/// Words of the number of thousands (millions, ...) followed by the noun agreeing with it.
+fn scale_words(morph: &MorphAnalyzer, scale: usize, triple: u16, case: Case) -> Vec<String> {
+    let lemma = SCALES[scale];
+    let noun = lexeme(morph, lemma, PartOfSpeach::Noun);
+    let gender = noun
+        .as_ref()
+        .and_then(|noun| noun.get_tag(morph).gender)
+        .unwrap_or(Gender::Masc);
+    let mut words = triple_words(morph, triple, NumeralForm::new(case, gender, Animacy::Inan));
+    let agreed = noun
+        .and_then(|noun| noun.inflect(morph, &noun_grammemes(case)))
+        .and_then(|noun| noun.get_plural(morph, triple));
+    words.push(match agreed {
+        Some(noun) => noun.get_word().into_owned(),
+        None => lemma.to_owned(),
+    });
+    words
+}
+
+/// The first part of a compound ordinal: "двух" (тысячный), "двадцатиодно" (тысячный).
+fn compound_prefix(morph: &MorphAnalyzer, triple: u16) -> String {
+    let gent = numeral_grammemes(NumeralForm {
+        case: Case::Gent,
+        ..NumeralForm::default()
+    });
+    components(triple)
+        .into_iter()
+        .map(|component| match component {
+            1 => "одно".to_owned(),
+            100 | 90 => cardinal_lemma(component).to_owned(),
+            _ => inflect(morph, cardinal_lemma(component), PartOfSpeach::Numr, &gent),
+        })
+        .collect()
+}
+
+fn numeral_grammemes(form: NumeralForm) -> GrammemeSet {
+    let mut set = hashset! { form.case.to_grammeme(), form.gender.to_grammeme() };
+    if form.case == Case::Accs {
+        set.insert(form.animacy.to_grammeme());
+    }
+    GrammemeSet { set }
+}
+
+fn ordinal_grammemes(form: NumeralForm) -> GrammemeSet {
+    let mut grammemes = numeral_grammemes(form);
+    grammemes.set.insert(Number::Sing.to_grammeme());
+    grammemes
+}
+
+fn noun_grammemes(case: Case) -> GrammemeSet {
+    GrammemeSet {
+        set: hashset! { case.to_grammeme(), Number::Sing.to_grammeme() },
+    }
+}
+
+/// Lexemes of the words numbers are spelled with, looked up once per analyzer:
+/// a lexeme of an integer spells it out in every case.
+///
+/// A clone of the cache is empty.
+#[derive(Default)]
+pub struct NumeralLexemes {
+    map: Mutex<HashMap<(String, PartOfSpeach), Option<Lex>>>,
+}
+
+impl NumeralLexemes {
+    /// The dictionary lexeme of the word with the part of speech.
+    pub fn get(&self, morph: &MorphAnalyzer, lemma: &str, pos: PartOfSpeach) -> Option<Lex> {
+        let key = (lemma.to_owned(), pos);
+        if let Some(lex) = self.lock().get(&key) {
+            return lex.clone();
+        }
+        let lex = morph
+            .parse(lemma)
+            .into_iter()
+            .find(|parsed| parsed.lex.get_tag(morph).pos == Some(pos))
+            .map(|parsed| parsed.lex);
+        self.lock().insert(key, lex.clone());
+        lex
+    }
+
+    pub fn clear(&self) {
+        self.lock().clear()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<(String, PartOfSpeach), Option<Lex>>> {
+        // The map is consistent even if a thread panicked while holding the lock
+        self.map.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Clone for NumeralLexemes {
+    fn clone(&self) -> Self {
+        NumeralLexemes::default()
+    }
+}
+
+impl fmt::Debug for NumeralLexemes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NumeralLexemes")
+            .field("len", &self.lock().len())
+            .finish()
+    }
+}
+
+/// The dictionary lexeme of the word with the part of speech.
+fn lexeme(morph: &MorphAnalyzer, lemma: &str, pos: PartOfSpeach) -> Option<Lex> {
+    morph.units.number.numerals.get(morph, lemma, pos)
+}
+
+/// Inflects the word; words missing in the dictionary are kept as they are.
+fn inflect(
+    morph: &MorphAnalyzer,
+    lemma: &str,
+    pos: PartOfSpeach,
+    required: &GrammemeSet,
+) -> String {
+    lexeme(morph, lemma, pos)
+        .and_then(|lex| lex.inflect(morph, required))
+        .map(|lex| lex.get_word().into_owned())
+        .unwrap_or_else(|| lemma.to_owned())
+}
+
+/// A numeral read from words by `parse_numeral`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParsedNumeral {
+    /// The value; "полтора" makes it fractional
+    pub value: f64,
+    pub ordinal: bool,
+    /// The case the numeral is written in, if the words agree on it
+    pub case: Option<Case>,
+    /// Known for the forms of "один", "два" and ordinals: "одной", "две", "первая"
+    pub gender: Option<Gender>,
+    /// The number of words taken
+    pub len: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Component {
+    Cardinal(f64),
+    Ordinal(f64),
+    /// A power of 1000 named by a noun
+    Scale(f64),
+}
+
+/// A word of a numeral with the tags of its parses having the numeral lemma.
+#[derive(Debug)]
+struct NumeralWord<'m> {
+    component: Component,
+    tags: Vec<&'m OpencorporaTagReg>,
+}
+
+/**
+    Reads a numeral from the beginning of the words: "двадцати трёх", "сто первого",
+    "полутора тысяч"; returns `None` if the first word is not a numeral.
+
+    Words are recognized by the normal forms of their parses, so any form
+    of a numeral is accepted; the case and the gender are of the parses the words agree on.
+    Reading stops at a word which doesn't continue the numeral, e.g. after an ordinal
+    or at "три" in "двадцать пять три".
+
+    ```no_run
+    use rsmorphy::{numbers::parse_numeral, opencorpora::kind::case::Case, MorphAnalyzer};
+
+    let morph = MorphAnalyzer::from_file(dict_ru::DICT_PATH);
+    let numeral = parse_numeral(&morph, &["полутора", "тысяч", "рублей"]).unwrap();
+    assert_eq!((numeral.value, numeral.case, numeral.len), (1500.0, Some(Case::Gent), 2));
+    ```
+*/
+pub fn parse_numeral<S>(morph: &MorphAnalyzer, words: &[S]) -> Option<ParsedNumeral>
+where
+    S: AsRef<str>,
+{
+    let (mut total, mut current) = (0.0, 0.0);
+    // The next scale must be less than `scale_limit`, and the next number in a group of three
+    // digits less than `limit`: "сто" may be followed by "двадцать", but not by "двести"
+    let (mut scale_limit, mut limit) = (f64::INFINITY, 1000.0);
+    let mut ordinal = false;
+    // Words whose form depends on the case of the numeral, the last one first
+    let mut agreeing: Vec<NumeralWord> = Vec::new();
+    let mut len = 0;
+
+    for word in words {
+        let word = match numeral_word(morph, word.as_ref()) {
+            Some(word) => word,
+            None => break,
+        };
+        match word.component {
+            Component::Scale(scale) if scale < scale_limit => {
+                // "пять тысяч": the noun agrees with the number, not with the case
+                if current == 0.0 {
+                    agreeing.insert(0, word);
+                }
+                total += if current == 0.0 { 1.0 } else { current } * scale;
+                current = 0.0;
+                scale_limit = scale;
+                limit = 1000.0;
+            }
+            Component::Cardinal(value) if value < limit && (value != 0.0 || len == 0) => {
+                current += value;
+                limit = match value {
+                    _ if value == 0.0 => 0.0,
+                    _ if value >= 100.0 && value % 100.0 == 0.0 => 100.0,
+                    _ if value >= 20.0 && value % 10.0 == 0.0 => 10.0,
+                    _ => 1.0,
+                };
+                agreeing.insert(0, word);
+            }
+            Component::Ordinal(value) if value >= 1000.0 => {
+                let scale = 1000_f64.powi(value.log10() as i32 / 3);
+                if current != 0.0 || scale >= scale_limit {
+                    break;
+                }
+                total += value;
+                ordinal = true;
+                // Cardinals before an ordinal stay nominative: "сто первого"
+                agreeing = vec![word];
+            }
+            Component::Ordinal(value) if value < limit && (value != 0.0 || len == 0) => {
+                current += value;
+                ordinal = true;
+                agreeing = vec![word];
+            }
+            _ => break,
+        }
+        len += 1;
+        if ordinal {
+            break;
+        }
+    }
+    if len == 0 {
+        return None;
+    }
+
+    let (case, gender) = agreed_form(&agreeing);
+    Some(ParsedNumeral {
+        value: total + current,
+        ordinal,
+        case,
+        gender,
+        len,
+    })
+}
+
+/// The case all the words may be in, the most probable for the last one,
+/// and the gender of the last word in the case.
+fn agreed_form(words: &[NumeralWord]) -> (Option<Case>, Option<Gender>) {
+    let last = match words.first() {
+        Some(last) => last,
+        None => return (None, None),
+    };
+    let has_case = |word: &NumeralWord, case: Case| {
+        word.tags
+            .iter()
+            .any(|tag| tag.case.map(Case::main) == Some(case))
+    };
+    let tag = last.tags.iter().find(|tag| match tag.case {
+        Some(case) => words.iter().all(|word| has_case(word, case.main())),
+        None => false,
+    });
+    match tag {
+        Some(tag) => (tag.case.map(Case::main), tag.gender),
+        None => (None, None),
+    }
+}
+
+fn numeral_word<'m>(morph: &'m MorphAnalyzer, word: &str) -> Option<NumeralWord<'m>> {
+    let parses = morph.parse(word);
+    let (component, lemma) = parses.iter().find_map(|parsed| {
+        let lemma = parsed.lex.get_normal_form(morph);
+        lemma_component(morph, &lemma).map(|component| (component, lemma.into_owned()))
+    })?;
+    let tags = parses
+        .iter()
+        .filter(|parsed| parsed.lex.get_normal_form(morph) == lemma)
+        .map(|parsed| parsed.lex.get_tag(morph))
+        .collect();
+    Some(NumeralWord { component, tags })
+}
+
+fn lemma_component(morph: &MorphAnalyzer, lemma: &str) -> Option<Component> {
+    let position = |table: &[&str]| table.iter().position(|&l| !l.is_empty() && l == lemma);
+    if let Some(n) = position(&UNITS) {
+        return Some(Component::Cardinal(n as f64));
+    }
+    if let Some(n) = position(&TENS) {
+        return Some(Component::Cardinal(n as f64 * 10.0));
+    }
+    if let Some(n) = position(&HUNDREDS) {
+        return Some(Component::Cardinal(n as f64 * 100.0));
+    }
+    if let Some(n) = position(&ORDINAL_UNITS) {
+        return Some(Component::Ordinal(n as f64));
+    }
+    if let Some(n) = position(&ORDINAL_TENS) {
+        return Some(Component::Ordinal(n as f64 * 10.0));
+    }
+    if let Some(n) = position(&SCALES) {
+        return Some(Component::Scale(1000_f64.powi(n as i32)));
+    }
+    match lemma {
+        "ноль" | "нуль" => return Some(Component::Cardinal(0.0)),
+        "нулевой" => return Some(Component::Ordinal(0.0)),
+        "полтора" => return Some(Component::Cardinal(1.5)),
+        "полтораста" => return Some(Component::Cardinal(150.0)),
+        _ => {}
+    }
+
+    // Compound ordinals: "двухсотый", "двадцатиоднотысячный"
+    let suffixes = SCALES
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(n, scale)| {
+            (
+                format!("{}ный", scale.trim_end_matches('а')),
+                1000_f64.powi(n as i32),
+            )
+        })
+        .chain(Some(("сотый".to_owned(), 100.0)));
+    for (suffix, scale) in suffixes {
+        if let Some(prefix) = lemma.strip_suffix(suffix.as_str()) {
+            let value = match prefix {
+                "" => Some(1.0),
+                _ => compound_prefix_value(morph, prefix),
+            };
+            return value.map(|value| Component::Ordinal(value * scale));
+        }
+    }
+    None
+}
+
+/// Reads the first part of a compound ordinal: "двух" (тысячный), "двадцатиодно" (тысячный).
+fn compound_prefix_value(morph: &MorphAnalyzer, prefix: &str) -> Option<f64> {
+    if prefix.is_empty() {
+        return Some(0.0);
+    }
+    prefix
+        .char_indices()
+        .skip(1)
+        .map(|(i, _)| i)
+        .chain(Some(prefix.len()))
+        .find_map(|i| {
+            let (head, rest) = prefix.split_at(i);
+            let value = morph.parse(head).iter().find_map(|parsed| {
+                match lemma_component(morph, &parsed.lex.get_normal_form(morph)) {
+                    Some(Component::Cardinal(value)) if value >= 1.0 => Some(value),
+                    _ => None,
+                }
+            })?;
+            compound_prefix_value(morph, rest).map(|rest| value + rest)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::opencorpora::kind::{animacy::Animacy, case::Case, gender::Gender};
+
+    lazy_static::lazy_static! {
+        static ref RU: MorphAnalyzer = MorphAnalyzer::from_file(dict_ru::DICT_PATH);
+    }
+
+    #[test]
+    fn spell_numbers() {
+        let form = NumeralForm::new;
+        let nomn = NumeralForm::default();
+        assert_eq!(cardinal(&RU, 0, nomn), "ноль");
+        assert_eq!(cardinal(&RU, 2023, nomn), "две тысячи двадцать три");
+        assert_eq!(cardinal(&RU, -1_000_001, nomn), "минус один миллион один");
+        let loct = form(Case::Loct, Gender::Masc, Animacy::Inan);
+        assert_eq!(cardinal(&RU, 23, loct), "двадцати трёх");
+        let datv = form(Case::Datv, Gender::Femn, Animacy::Inan);
+        assert_eq!(cardinal(&RU, 5000, datv), "пяти тысячам");
+        assert_eq!(cardinal(&RU, 1001, datv), "одной тысяче одной");
+        let accs = form(Case::Accs, Gender::Masc, Animacy::Anim);
+        assert_eq!(cardinal(&RU, 2, accs), "двух");
+        assert_eq!(cardinal(&RU, 22, accs), "двадцать два");
+        assert_eq!(cardinal(&RU, 21, accs), "двадцать одного");
+        let accs = form(Case::Accs, Gender::Femn, Animacy::Inan);
+        assert_eq!(cardinal(&RU, 1, accs), "одну");
+        assert_eq!(cardinal(&RU, 2000, accs), "две тысячи");
+
+        assert_eq!(ordinal(&RU, 0, nomn), "нулевой");
+        assert_eq!(ordinal(&RU, 2023, nomn), "две тысячи двадцать третий");
+        assert_eq!(ordinal(&RU, 40, nomn), "сороковой");
+        assert_eq!(ordinal(&RU, 120, nomn), "сто двадцатый");
+        assert_eq!(ordinal(&RU, 300, loct), "трёхсотом");
+        assert_eq!(ordinal(&RU, 1000, nomn), "тысячный");
+        assert_eq!(ordinal(&RU, 21000, nomn), "двадцатиоднотысячный");
+        assert_eq!(ordinal(&RU, 3_000_000, nomn), "трёхмиллионный");
+        let femn = form(Case::Nomn, Gender::Femn, Animacy::Inan);
+        assert_eq!(ordinal(&RU, 2000, femn), "двухтысячная");
+        let accs = form(Case::Accs, Gender::Masc, Animacy::Anim);
+        assert_eq!(ordinal(&RU, 1, accs), "первого");
+    }
+
+    #[test]
+    fn parse_numerals() {
+        let parse = |text: &str| -> (f64, bool, Option<Case>, Option<Gender>, usize) {
+            let words: Vec<&str> = text.split(' ').collect();
+            let numeral = parse_numeral(&RU, &words).unwrap();
+            (
+                numeral.value,
+                numeral.ordinal,
+                numeral.case,
+                numeral.gender,
+                numeral.len,
+            )
+        };
+        assert_eq!(
+            parse("двадцати трёх случаях"),
+            (23.0, false, Some(Case::Gent), None, 2)
+        );
+        assert_eq!(
+            parse("сто первого дня"),
+            (101.0, true, Some(Case::Gent), Some(Gender::Masc), 2)
+        );
+        assert_eq!(
+            parse("полутора тысяч"),
+            (1500.0, false, Some(Case::Gent), None, 2)
+        );
+        assert_eq!(
+            parse("две тысячи двадцать третья"),
+            (2023.0, true, Some(Case::Nomn), Some(Gender::Femn), 4)
+        );
+        assert_eq!(
+            parse("двум тысячам"),
+            (2000.0, false, Some(Case::Datv), None, 2)
+        );
+        assert_eq!(
+            parse("две книги"),
+            (2.0, false, Some(Case::Nomn), Some(Gender::Femn), 1)
+        );
+        assert_eq!(parse("двухтысячного").0, 2000.0);
+        assert_eq!(parse("двадцать пять три").0, 25.0);
+        assert_eq!(parse("ноль").0, 0.0);
+        assert!(parse_numeral(&RU, &["книги"]).is_none());
+        assert!(parse_numeral::<&str>(&RU, &[]).is_none());
+    }
+}
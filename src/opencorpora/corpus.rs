@@ -0,0 +1,215 @@
+use std::{error::Error, fmt, io};
+
+use quick_xml::{events::Event, Reader};
+
+/// A word of an annotated corpus with its OpenCorpora tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaggedWord {
+    pub word: String,
+    pub tag: String,
+}
+
+impl TaggedWord {
+    pub fn new<W, T>(word: W, tag: T) -> Self
+    where
+        W: Into<String>,
+        T: Into<String>,
+    {
+        TaggedWord {
+            word: word.into(),
+            tag: tag.into(),
+        }
+    }
+}
+
+pub type Sentence = Vec<TaggedWord>;
+
+/**
+    Reads sentences of an OpenCorpora XML corpus.
+
+    Only disambiguated tokens (having a single variant of a parse) are taken;
+    the tag is made of the grammemes of the variant.
+
+    ```
+    use rsmorphy::opencorpora::corpus::{read_xml, TaggedWord};
+
+    let xml = r#"<annotation><text><paragraphs><paragraph>
+        <sentence id="1"><source>Мы стали</source><tokens>
+            <token id="1" text="Мы"><tfr t="Мы"><v><l id="1" t="мы">
+                <g v="NPRO"/><g v="1per"/><g v="plur"/><g v="nomn"/>
+            </l></v></tfr></token>
+            <token id="2" text="стали"><tfr t="стали">
+                <v><l id="2" t="стать"><g v="VERB"/><g v="plur"/></l></v>
+                <v><l id="3" t="сталь"><g v="NOUN"/><g v="plur"/></l></v>
+            </tfr></token>
+        </tokens></sentence>
+    </paragraph></paragraphs></text></annotation>"#;
+
+    let sentences = read_xml(xml.as_bytes()).unwrap();
+    assert_eq!(sentences, vec![vec![TaggedWord::new("Мы", "NPRO,1per,plur,nomn")]]);
+    ```
+*/
+pub fn read_xml<R>(reader: R) -> Result<Vec<Sentence>, CorpusError>
+where
+    R: io::BufRead,
+{
+    let mut reader = Reader::from_reader(reader);
+    let mut buf = Vec::new();
+    let mut sentences = Vec::new();
+    let mut sentence = Sentence::new();
+    // The text of the current token, the number of its variants and the grammemes of the first one
+    let mut token: Option<(String, usize, Vec<String>)> = None;
+    loop {
+        match reader.read_event_into(&mut buf)? {
+            Event::Start(e) | Event::Empty(e) => match (e.name().as_ref(), &mut token) {
+                (b"token", _) => {
+                    let text = match e.try_get_attribute("text")? {
+                        Some(text) => text.unescape_value()?.into_owned(),
+                        None => return Err(malformed(&reader, "a token without text")),
+                    };
+                    token = Some((text, 0, Vec::new()));
+                }
+                (b"v", Some((_, variants, _))) => *variants += 1,
+                (b"g", Some((_, 1, grammemes))) => {
+                    if let Some(grammeme) = e.try_get_attribute("v")? {
+                        grammemes.push(grammeme.unescape_value()?.into_owned());
+                    }
+                }
+                _ => {}
+            },
+            Event::End(e) => match e.name().as_ref() {
+                b"token" => {
+                    if let Some((text, 1, grammemes)) = token.take() {
+                        if !grammemes.is_empty() {
+                            sentence.push(TaggedWord::new(text, grammemes.join(",")));
+                        }
+                    }
+                }
+                b"sentence" if !sentence.is_empty() => {
+                    sentences.push(sentence);
+                    sentence = Sentence::new();
+                }
+                _ => {}
+            },
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+    Ok(sentences)
+}
+
+/**
+    Reads sentences of a CoNLL-U corpus; the OpenCorpora tag is taken from the XPOS column.
+
+    Tokens without XPOS, multiword tokens and empty nodes are skipped.
+
+    ```
+    use rsmorphy::opencorpora::corpus::{read_conllu, TaggedWord};
+
+    let conllu = "# text = Мы стали\n\
+        1\tМы\tмы\tPRON\tNPRO,1per plur,nomn\t_\t2\tnsubj\t_\t_\n\
+        2\tстали\tстать\tVERB\t_\t_\t0\troot\t_\t_\n\
+        \n";
+
+    let sentences = read_conllu(conllu.as_bytes()).unwrap();
+    assert_eq!(sentences, vec![vec![TaggedWord::new("Мы", "NPRO,1per plur,nomn")]]);
+    ```
+*/
+pub fn read_conllu<R>(reader: R) -> Result<Vec<Sentence>, CorpusError>
+where
+    R: io::BufRead,
+{
+    let mut sentences = Vec::new();
+    let mut sentence = Sentence::new();
+    for (line_no, line) in reader.lines().enumerate() {
+        let line = line?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            if !sentence.is_empty() {
+                sentences.push(sentence);
+                sentence = Sentence::new();
+            }
+            continue;
+        }
+        if line.starts_with('#') {
+            continue;
+        }
+        let columns: Vec<&str> = line.split('\t').collect();
+        if columns.len() != 10 {
+            return Err(CorpusError::Malformed {
+                position: line_no + 1,
+                reason: "a token line must have 10 columns",
+            });
+        }
+        let (id, form, xpos) = (columns[0], columns[1], columns[4]);
+        if id.contains(&['-', '.'][..]) || xpos == "_" {
+            continue;
+        }
+        sentence.push(TaggedWord::new(form, xpos));
+    }
+    if !sentence.is_empty() {
+        sentences.push(sentence);
+    }
+    Ok(sentences)
+}
+
+fn malformed<R>(reader: &Reader<R>, reason: &'static str) -> CorpusError {
+    CorpusError::Malformed {
+        position: reader.buffer_position(),
+        reason,
+    }
+}
+
+/// An error occurred while reading an annotated corpus.
+#[derive(Debug)]
+pub enum CorpusError {
+    Io(io::Error),
+    Xml(quick_xml::Error),
+    /// The corpus doesn't have the expected structure;
+    /// the position is a byte offset for XML and a line number for CoNLL-U
+    Malformed {
+        position: usize,
+        reason: &'static str,
+    },
+}
+
+impl From<io::Error> for CorpusError {
+    fn from(e: io::Error) -> Self {
+        CorpusError::Io(e)
+    }
+}
+
+impl From<quick_xml::Error> for CorpusError {
+    fn from(e: quick_xml::Error) -> Self {
+        CorpusError::Xml(e)
+    }
+}
+
+impl From<quick_xml::events::attributes::AttrError> for CorpusError {
+    fn from(e: quick_xml::events::attributes::AttrError) -> Self {
+        CorpusError::Xml(e.into())
+    }
+}
+
+impl fmt::Display for CorpusError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CorpusError::Io(e) => write!(f, "{}", e),
+            CorpusError::Xml(e) => write!(f, "malformed XML ({})", e),
+            CorpusError::Malformed { position, reason } => {
+                write!(f, "malformed corpus at {}: {}", position, reason)
+            }
+        }
+    }
+}
+
+impl Error for CorpusError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CorpusError::Io(e) => Some(e),
+            CorpusError::Xml(e) => Some(e),
+            CorpusError::Malformed { .. } => None,
+        }
+    }
+}
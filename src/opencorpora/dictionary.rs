@@ -3,13 +3,12 @@ use std::{
     collections::{BTreeMap, HashMap, HashSet},
     fmt,
     fs::File,
-    io::Read,
+    io::{self, Read},
     iter::FromIterator,
     path::{Path, PathBuf},
 };
 
 use byteorder::{LittleEndian, ReadBytesExt};
-use flate2::read::GzDecoder;
 use maplit::hashset;
 use serde_json;
 use serde_json::Value;
@@ -19,7 +18,9 @@ pub use crate::dawg::{HH, HHH};
 use crate::{
     container::paradigm::{ParadigmId, ParadigmIndex},
     dawg::{CompletionDawg, Dawg},
+    language::Language,
     opencorpora::{
+        error::{GzReader, LoadError},
         grammeme::{Grammeme, GrammemeReg},
         paradigm::ParadigmEntry,
         tag::OpencorporaTagReg,
@@ -42,6 +43,7 @@ pub struct GrammemeMeta {
 #[derive(Debug, Clone)]
 pub struct Dictionary {
     pub meta: HashMap<String, Value>,
+    pub language: Language,
     pub grammemes: HashMap<Grammeme, GrammemeReg>,
     pub grammeme_metas: HashMap<Grammeme, GrammemeMeta>,
     pub gramtab: Vec<OpencorporaTagReg>,
@@ -54,6 +56,8 @@ pub struct Dictionary {
     pub paradigm_prefixes: Vec<String>,
     pub paradigm_prefixes_rev: Vec<(u16, String)>,
     pub prediction_splits: Vec<usize>,
+    /// Letters which may be written instead of others, e.g. "е" instead of "ё";
+    /// `MorphAnalyzer` uses them unless overridden.
     pub char_substitutes: BTreeMap<String, String>,
 }
 
@@ -77,24 +81,49 @@ impl PathLoader {
         self.dict_path.join(name)
     }
 
-    fn reader<S>(&self, name: S) -> impl Read
+    fn reader<S>(&self, name: S) -> Result<GzReader<File>, LoadError>
     where
         S: AsRef<Path>,
     {
-        GzDecoder::new(File::open(&self.path(name)).unwrap())
+        let path = self.path(name);
+        match File::open(&path) {
+            Ok(file) => Ok(GzReader::new(file)),
+            Err(e) => Err(LoadError::from_io(path, e)),
+        }
     }
 
-    fn json<S, T>(&self, name: S) -> serde_json::Result<T>
+    fn read<S, T, F>(&self, name: S, f: F) -> Result<T, LoadError>
+    where
+        S: AsRef<Path>,
+        F: FnOnce(&mut GzReader<File>) -> io::Result<T>,
+    {
+        let name = name.as_ref();
+        f(&mut self.reader(name)?).map_err(|e| LoadError::from_io(self.path(name), e))
+    }
+
+    fn json<S, T>(&self, name: S) -> Result<T, LoadError>
     where
         S: AsRef<Path>,
         for<'de> T: ::serde::Deserialize<'de>,
     {
-        serde_json::from_reader(self.reader(name))
+        let name = name.as_ref();
+        serde_json::from_reader(self.reader(name)?)
+            .map_err(|e| LoadError::from_json(self.path(name), e))
     }
 }
 
 impl Dictionary {
+    /// Loads the dictionary from `p` and panics if it fails.
+    ///
+    /// See `try_from_file` for a fallible version.
     pub fn from_file<P>(p: P) -> Self
+    where
+        P: AsRef<Path>,
+    {
+        Self::try_from_file(p).unwrap_or_else(|e| panic!("Can't load the dictionary: {}", e))
+    }
+
+    pub fn try_from_file<P>(p: P) -> Result<Self, LoadError>
     where
         P: AsRef<Path>,
     {
@@ -102,32 +131,45 @@ impl Dictionary {
 
         let mut profiler = DumbProfiler::start();
 
-        let meta: Vec<(String, Value)> = load.json("meta.json.gz").expect("object of `Value`s");
+        let meta: Vec<(String, Value)> = load.json("meta.json.gz")?;
         let meta = HashMap::from_iter(meta.into_iter());
         profiler.waypoint("meta");
 
-        let paradigm_prefixes: Vec<String> = {
-            meta["compile_options"]
-                .as_object()
-                .unwrap()
-                .get("paradigm_prefixes")
-                .unwrap()
-                .as_array()
-                .unwrap()
-                .iter()
-                .map(|v| v.as_str().unwrap().to_owned())
-                .collect()
+        let malformed_meta = |field| LoadError::MalformedMeta {
+            file: load.path("meta.json.gz"),
+            field,
+        };
+
+        // Dictionaries compiled before the language code was stored are Russian.
+        let language = match meta.get("language_code") {
+            None => Language::Russian,
+            Some(code) => code
+                .as_str()
+                .and_then(Language::from_code)
+                .ok_or_else(|| malformed_meta("language_code"))?,
         };
-        let max_suffix_length = {
+
+        let compile_options = meta
+            .get("compile_options")
+            .and_then(Value::as_object)
+            .ok_or_else(|| malformed_meta("compile_options"))?;
+        let paradigm_prefixes: Vec<String> = compile_options
+            .get("paradigm_prefixes")
+            .and_then(Value::as_array)
+            .and_then(|prefixes| {
+                prefixes
+                    .iter()
+                    .map(|v| v.as_str().map(ToOwned::to_owned))
+                    .collect()
+            })
+            .ok_or_else(|| malformed_meta("paradigm_prefixes"))?;
+        let max_suffix_length =
             meta.get("prediction_options")
-                .unwrap_or_else(|| &meta["compile_options"])
-                .as_object()
-                .unwrap()
+                .and_then(Value::as_object)
+                .unwrap_or(compile_options)
                 .get("max_suffix_length")
-                .unwrap()
-                .as_u64()
-                .unwrap() as usize
-        };
+                .and_then(Value::as_u64)
+                .ok_or_else(|| malformed_meta("max_suffix_length"))? as usize;
         let prediction_splits = (1..=max_suffix_length).rev().collect();
         profiler.waypoint("meta'");
 
@@ -139,19 +181,18 @@ impl Dictionary {
             .collect();
         profiler.waypoint("paradigm_prefixes_rev");
 
-        let suffixes = load.json("suffixes.json.gz").expect("array of strings");
+        let suffixes: Vec<String> = load.json("suffixes.json.gz")?;
         profiler.waypoint("suffixes");
 
-        let gramtab: Vec<String> = load
-            .json("gramtab-opencorpora-int.json.gz")
-            .expect("array of strings");
+        let gramtab: Vec<String> = load.json("gramtab-opencorpora-int.json.gz")?;
         profiler.waypoint("gramtab");
         // TODO opencorpora-ext
-        let gramtab = gramtab.into_iter().map(OpencorporaTagReg::new).collect();
+        let gramtab: Vec<OpencorporaTagReg> =
+            gramtab.into_iter().map(OpencorporaTagReg::new).collect();
         profiler.waypoint("gramtab'");
 
         // TODO join `grammemes` and `grammeme_metas` into one set
-        let grammemes: Vec<Vec<Value>> = load.json("grammemes.json.gz").expect("array of `Value`s");
+        let grammemes: Vec<Vec<Value>> = load.json("grammemes.json.gz")?;
         profiler.waypoint("grammemes");
         let grammemes = grammemes
             .into_iter()
@@ -201,27 +242,45 @@ impl Dictionary {
         };
         profiler.waypoint("grammemes'");
 
-        let paradigms = load_paradigms(&mut load.reader("paradigms.array.gz"));
+        let paradigms = load.read("paradigms.array.gz", load_paradigms)?;
+        let paradigms = check_paradigms(
+            paradigms,
+            gramtab.len(),
+            suffixes.len(),
+            paradigm_prefixes.len(),
+        )
+        .map_err(|(paradigm_id, reason)| LoadError::InconsistentParadigms {
+            file: load.path("paradigms.array.gz"),
+            paradigm_id,
+            reason,
+        })?;
         profiler.waypoint("paradigms");
-        let words = CompletionDawg::from_reader(&mut load.reader("words.dawg.gz"));
+        let words = load.read("words.dawg.gz", CompletionDawg::try_from_reader)?;
         profiler.waypoint("words");
-        let p_t_given_w = CompletionDawg::from_reader(&mut load.reader("p_t_given_w.intdawg.gz"));
+        let p_t_given_w = load.read("p_t_given_w.intdawg.gz", CompletionDawg::try_from_reader)?;
         profiler.waypoint("p_t_given_w");
         let prediction_prefixes =
-            Dawg::from_reader(&mut load.reader("prediction-prefixes.dawg.gz"));
+            load.read("prediction-prefixes.dawg.gz", Dawg::try_from_reader)?;
         profiler.waypoint("prediction_prefixes");
-        let prediction_suffixes_dawgs = Vec::from_iter((0..paradigm_prefixes.len()).map(|i| {
-            CompletionDawg::from_reader(
-                &mut load.reader(format!("prediction-suffixes-{}.dawg.gz", i)),
-            )
-        }));
+        let prediction_suffixes_dawgs = (0..paradigm_prefixes.len())
+            .map(|i| {
+                load.read(
+                    format!("prediction-suffixes-{}.dawg.gz", i),
+                    CompletionDawg::try_from_reader,
+                )
+            })
+            .collect::<Result<_, _>>()?;
         profiler.waypoint("prediction_suffixes_dawgs");
 
-        // TODO load char_substitutes
-        let char_substitutes = maplit::btreemap! {"е".into() => "ё".into()};
+        let char_substitutes = match meta.get("char_substitutes") {
+            None => language.char_substitutes(),
+            Some(substitutes) => parse_char_substitutes(substitutes)
+                .ok_or_else(|| malformed_meta("char_substitutes"))?,
+        };
 
-        Dictionary {
+        Ok(Dictionary {
             meta,
+            language,
             grammemes,
             grammeme_metas,
             gramtab,
@@ -235,7 +294,7 @@ impl Dictionary {
             paradigm_prefixes_rev,
             prediction_splits,
             char_substitutes,
-        }
+        })
     }
 
     pub fn get_paradigm<Id>(&self, id: Id) -> &[ParadigmEntry]
@@ -329,15 +388,188 @@ impl Dictionary {
     }
 }
 
-fn load_paradigms<R: Read>(reader: &mut R) -> Vec<Vec<ParadigmEntry>> {
-    let paradigms_count = reader.read_u16::<LittleEndian>().unwrap();
+/// Parses substitutes given either as `{"е": "ё"}` or as `[["е", "ё"]]`.
+fn parse_char_substitutes(value: &Value) -> Option<BTreeMap<String, String>> {
+    match value {
+        Value::Object(map) => map
+            .iter()
+            .map(|(from, to)| Some((from.clone(), to.as_str()?.to_owned())))
+            .collect(),
+        Value::Array(pairs) => pairs
+            .iter()
+            .map(|pair| match pair.as_array()?.as_slice() {
+                [from, to] => Some((from.as_str()?.to_owned(), to.as_str()?.to_owned())),
+                _ => None,
+            })
+            .collect(),
+        _ => None,
+    }
+}
+
+fn load_paradigms<R: Read>(reader: &mut R) -> io::Result<Vec<Vec<u16>>> {
+    let paradigms_count = reader.read_u16::<LittleEndian>()?;
     (0..paradigms_count)
         .map(|_| {
-            let paradigm_len = reader.read_u16::<LittleEndian>().unwrap();
+            let paradigm_len = reader.read_u16::<LittleEndian>()?;
             (0..paradigm_len)
-                .map(|_| reader.read_u16::<LittleEndian>().unwrap())
-                .collect::<Vec<u16>>()
+                .map(|_| reader.read_u16::<LittleEndian>())
+                .collect()
+        })
+        .collect()
+}
+
+/// Builds paradigms from raw arrays and checks every entry refers to an existing tag,
+/// suffix and prefix; returns the id of the first broken paradigm otherwise.
+fn check_paradigms(
+    paradigms: Vec<Vec<u16>>,
+    gramtab_len: usize,
+    suffixes_len: usize,
+    prefixes_len: usize,
+) -> Result<Vec<Vec<ParadigmEntry>>, (usize, &'static str)> {
+    paradigms
+        .into_iter()
+        .enumerate()
+        .map(|(paradigm_id, paradigm)| {
+            if paradigm.is_empty() || paradigm.len() % 3 != 0 {
+                return Err((paradigm_id, "wrong paradigm length"));
+            }
+            let paradigm = ParadigmEntry::build(paradigm);
+            for entry in &paradigm {
+                if entry.tag_id as usize >= gramtab_len {
+                    return Err((paradigm_id, "tag id is out of the gramtab"));
+                }
+                if entry.suffix_id as usize >= suffixes_len {
+                    return Err((paradigm_id, "suffix id is out of the suffixes"));
+                }
+                if entry.prefix_id as usize >= prefixes_len {
+                    return Err((paradigm_id, "prefix id is out of the paradigm prefixes"));
+                }
+            }
+            Ok(paradigm)
         })
-        .map(ParadigmEntry::build)
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, io::Write, path::PathBuf, process};
+
+    use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+    use serde_json::Value;
+
+    use super::*;
+
+    #[test]
+    fn load_missing() {
+        match Dictionary::try_from_file("/nonexistent/dict") {
+            Err(LoadError::Missing { ref file, .. }) => assert!(file.ends_with("meta.json.gz")),
+            Err(e) => panic!("unexpected error: {}", e),
+            Ok(_) => panic!("the dictionary should not be loaded"),
+        }
+    }
+
+    /// Copies the Russian dictionary into a temporary directory and replaces `file` there
+    /// with a gzipped `data` (or with `data` as is when `gzip` is false).
+    fn broken_dict(name: &str, file: &str, data: &[u8], gzip: bool) -> PathBuf {
+        let dir = env::temp_dir().join(format!("rsmorphy-{}-{}", name, process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for entry in fs::read_dir(dict_ru::DICT_PATH).unwrap() {
+            let entry = entry.unwrap();
+            fs::copy(entry.path(), dir.join(entry.file_name())).unwrap();
+        }
+        let mut out = fs::File::create(dir.join(file)).unwrap();
+        if gzip {
+            let mut encoder = GzEncoder::new(out, Compression::default());
+            encoder.write_all(data).unwrap();
+            encoder.finish().unwrap();
+        } else {
+            out.write_all(data).unwrap();
+        }
+        dir
+    }
+
+    fn load_error(dir: PathBuf) -> LoadError {
+        let result = Dictionary::try_from_file(&dir);
+        fs::remove_dir_all(&dir).unwrap();
+        match result {
+            Err(e) => e,
+            Ok(_) => panic!("the dictionary should not be loaded"),
+        }
+    }
+
+    #[test]
+    fn load_truncated() {
+        // Two paradigms are announced, the first one is cut in the middle
+        let data = [2, 0, 3, 0, 0, 0];
+        match load_error(broken_dict("truncated", "paradigms.array.gz", &data, true)) {
+            LoadError::Truncated { ref file, .. } => {
+                assert!(file.ends_with("paradigms.array.gz"))
+            }
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn load_bad_gzip() {
+        let data = b"not a gzip stream";
+        match load_error(broken_dict("bad-gzip", "words.dawg.gz", data, false)) {
+            LoadError::BadGzip { ref file, .. } => assert!(file.ends_with("words.dawg.gz")),
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn load_inconsistent_paradigm() {
+        // The second paradigm refers to a tag which is not in the gramtab
+        let data = [2, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0xff, 0xff, 0, 0];
+        match load_error(broken_dict(
+            "inconsistent",
+            "paradigms.array.gz",
+            &data,
+            true,
+        )) {
+            LoadError::InconsistentParadigms {
+                ref file,
+                paradigm_id,
+                reason,
+            } => {
+                assert!(file.ends_with("paradigms.array.gz"));
+                assert_eq!(paradigm_id, 1);
+                assert_eq!(reason, "tag id is out of the gramtab");
+            }
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    /// The meta of the Russian dictionary with `language_code` replaced (or removed).
+    fn meta_with_language(code: Option<&str>) -> Vec<u8> {
+        let file = fs::File::open(PathBuf::from(dict_ru::DICT_PATH).join("meta.json.gz")).unwrap();
+        let mut meta: Vec<(String, Value)> = serde_json::from_reader(GzDecoder::new(file)).unwrap();
+        meta.retain(|(key, _)| key != "language_code");
+        if let Some(code) = code {
+            meta.push(("language_code".into(), code.into()));
+        }
+        serde_json::to_vec(&meta).unwrap()
+    }
+
+    #[test]
+    fn load_unknown_language() {
+        let data = meta_with_language(Some("xx"));
+        match load_error(broken_dict("unknown-language", "meta.json.gz", &data, true)) {
+            LoadError::MalformedMeta { ref file, field } => {
+                assert!(file.ends_with("meta.json.gz"));
+                assert_eq!(field, "language_code");
+            }
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn load_without_language() {
+        let data = meta_with_language(None);
+        let dir = broken_dict("no-language", "meta.json.gz", &data, true);
+        let result = Dictionary::try_from_file(&dir);
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(result.unwrap().language, Language::Russian);
+    }
+}
@@ -0,0 +1,228 @@
+use std::{
+    error::Error,
+    fmt,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use flate2::read::GzDecoder;
+
+/// An error occurred while loading a compiled dictionary or another data file.
+///
+/// Every variant names the offending file.
+#[derive(Debug)]
+pub enum LoadError {
+    /// The file doesn't exist or can't be opened
+    Missing { file: PathBuf, cause: io::Error },
+    /// The file is not a valid gzip stream
+    BadGzip { file: PathBuf, cause: io::Error },
+    /// The file (a DAWG or an array) ends prematurely
+    Truncated { file: PathBuf, cause: io::Error },
+    /// The content of the file doesn't match its format (e.g. it isn't valid UTF-8)
+    MalformedData { file: PathBuf, cause: io::Error },
+    /// The file can't be parsed as JSON of the expected structure
+    MalformedJson {
+        file: PathBuf,
+        cause: serde_json::Error,
+    },
+    /// A required field of `meta.json` is absent or has a wrong type
+    MalformedMeta { file: PathBuf, field: &'static str },
+    /// Paradigms refer to tags, suffixes or prefixes which don't exist
+    InconsistentParadigms {
+        file: PathBuf,
+        paradigm_id: usize,
+        reason: &'static str,
+    },
+    /// A line of a text list (e.g. of abbreviations) is malformed
+    MalformedList {
+        file: PathBuf,
+        line: usize,
+        reason: &'static str,
+    },
+    /// Any other I/O error
+    Io { file: PathBuf, cause: io::Error },
+}
+
+impl LoadError {
+    /// Classifies an I/O error occurred while reading `file`.
+    pub fn from_io<P>(file: P, cause: io::Error) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        let file = file.into();
+        let from_gzip = cause
+            .get_ref()
+            .map_or(false, |inner| inner.is::<GzipError>());
+        match cause.kind() {
+            io::ErrorKind::NotFound => LoadError::Missing { file, cause },
+            io::ErrorKind::UnexpectedEof => LoadError::Truncated { file, cause },
+            _ if from_gzip => LoadError::BadGzip { file, cause },
+            io::ErrorKind::InvalidInput | io::ErrorKind::InvalidData => {
+                LoadError::MalformedData { file, cause }
+            }
+            _ => LoadError::Io { file, cause },
+        }
+    }
+
+    /// Classifies a JSON error occurred while reading `file`.
+    pub fn from_json<P>(file: P, cause: serde_json::Error) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        if cause.is_io() {
+            LoadError::from_io(file, cause.into())
+        } else {
+            LoadError::MalformedJson {
+                file: file.into(),
+                cause,
+            }
+        }
+    }
+
+    /// The offending file.
+    pub fn file(&self) -> &Path {
+        match self {
+            LoadError::Missing { file, .. }
+            | LoadError::BadGzip { file, .. }
+            | LoadError::Truncated { file, .. }
+            | LoadError::MalformedData { file, .. }
+            | LoadError::MalformedJson { file, .. }
+            | LoadError::MalformedMeta { file, .. }
+            | LoadError::InconsistentParadigms { file, .. }
+            | LoadError::MalformedList { file, .. }
+            | LoadError::Io { file, .. } => file,
+        }
+    }
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LoadError::Missing { file, cause } => {
+                write!(f, "{}: missing file ({})", file.display(), cause)
+            }
+            LoadError::BadGzip { file, cause } => {
+                write!(f, "{}: bad gzip stream ({})", file.display(), cause)
+            }
+            LoadError::Truncated { file, cause } => {
+                write!(f, "{}: truncated file ({})", file.display(), cause)
+            }
+            LoadError::MalformedData { file, cause } => {
+                write!(f, "{}: malformed data ({})", file.display(), cause)
+            }
+            LoadError::MalformedJson { file, cause } => {
+                write!(f, "{}: malformed JSON ({})", file.display(), cause)
+            }
+            LoadError::MalformedMeta { file, field } => write!(
+                f,
+                "{}: field `{}` is absent or malformed",
+                file.display(),
+                field
+            ),
+            LoadError::InconsistentParadigms {
+                file,
+                paradigm_id,
+                reason,
+            } => write!(
+                f,
+                "{}: inconsistent paradigm {:#x} ({})",
+                file.display(),
+                paradigm_id,
+                reason
+            ),
+            LoadError::MalformedList { file, line, reason } => {
+                write!(f, "{}:{}: {}", file.display(), line, reason)
+            }
+            LoadError::Io { file, cause } => write!(f, "{}: {}", file.display(), cause),
+        }
+    }
+}
+
+impl Error for LoadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            LoadError::Missing { cause, .. }
+            | LoadError::BadGzip { cause, .. }
+            | LoadError::Truncated { cause, .. }
+            | LoadError::MalformedData { cause, .. }
+            | LoadError::Io { cause, .. } => Some(cause),
+            LoadError::MalformedJson { cause, .. } => Some(cause),
+            LoadError::MalformedMeta { .. }
+            | LoadError::InconsistentParadigms { .. }
+            | LoadError::MalformedList { .. } => None,
+        }
+    }
+}
+
+/// An error of the gzip decoder, as opposed to errors of the decompressed data.
+#[derive(Debug)]
+struct GzipError(io::Error);
+
+impl fmt::Display for GzipError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Error for GzipError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+/// A gzip decoder marking its own errors, so `LoadError::from_io` reports them as `BadGzip`.
+#[derive(Debug)]
+pub struct GzReader<R>(GzDecoder<R>);
+
+impl<R: Read> GzReader<R> {
+    pub fn new(reader: R) -> Self {
+        GzReader(GzDecoder::new(reader))
+    }
+}
+
+impl<R: Read> Read for GzReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.read(buf).map_err(|e| match e.kind() {
+            io::ErrorKind::InvalidInput | io::ErrorKind::InvalidData => {
+                io::Error::new(e.kind(), GzipError(e))
+            }
+            _ => e,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use flate2::{write::GzEncoder, Compression};
+
+    use super::*;
+
+    fn read_gzipped(data: &[u8]) -> LoadError {
+        let mut text = String::new();
+        let cause = GzReader::new(data).read_to_string(&mut text).unwrap_err();
+        LoadError::from_io("file.gz", cause)
+    }
+
+    #[test]
+    fn from_io() {
+        match read_gzipped(b"not a gzip stream") {
+            LoadError::BadGzip { .. } => {}
+            e => panic!("unexpected error: {}", e),
+        }
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(b"\xff\xfe invalid utf-8").unwrap();
+        match read_gzipped(&encoder.finish().unwrap()) {
+            LoadError::MalformedData { .. } => {}
+            e => panic!("unexpected error: {}", e),
+        }
+
+        let cause = io::Error::new(io::ErrorKind::InvalidData, "bad DAWG");
+        match LoadError::from_io("file.dawg", cause) {
+            LoadError::MalformedData { ref file, .. } => assert!(file.ends_with("file.dawg")),
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+}
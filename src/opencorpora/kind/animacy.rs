@@ -1,3 +1,5 @@
+use crate::opencorpora::Grammeme;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Animacy {
     /// одушевлённое
@@ -33,6 +35,14 @@ impl Animacy {
             })
     }
 
+    pub fn to_grammeme(self) -> Grammeme {
+        use self::Animacy::*;
+        match self {
+            Anim => Grammeme::new("anim"),
+            Inan => Grammeme::new("inan"),
+        }
+    }
+
     pub fn title_rus(self) -> &'static str {
         use self::Animacy::*;
 
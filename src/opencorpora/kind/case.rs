@@ -94,6 +94,19 @@ impl Case {
         }
     }
 
+    /// Returns the main case of a variant: genitive for the second genitive,
+    /// locative for the second locative and so on.
+    pub fn main(self) -> Self {
+        use self::Case::*;
+
+        match self {
+            Gen1 | Gen2 => Gent,
+            Acc2 => Accs,
+            Loc1 | Loc2 => Loct,
+            case => case,
+        }
+    }
+
     pub fn title_rus(self) -> &'static str {
         use self::Case::*;
 
@@ -1,3 +1,5 @@
+use crate::opencorpora::Grammeme;
+
 /// Род
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Gender {
@@ -37,6 +39,15 @@ impl Gender {
             })
     }
 
+    pub fn to_grammeme(self) -> Grammeme {
+        use self::Gender::*;
+        match self {
+            Masc => Grammeme::new("masc"),
+            Femn => Grammeme::new("femn"),
+            Neut => Grammeme::new("neut"),
+        }
+    }
+
     pub fn title_rus(self) -> &'static str {
         use self::Gender::*;
 
@@ -0,0 +1,326 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    error::Error,
+    fmt,
+};
+
+use crate::{
+    container::paradigm::{ParadigmId, ParadigmIndex},
+    dawg::HH,
+    opencorpora::{Dictionary, GrammemeSet, OpencorporaTagReg},
+};
+
+/// A form of a word registered in `UserLexicon`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UserEntry {
+    /// The word is inflected by a paradigm of the compiled dictionary
+    Paradigm(ParadigmId, ParadigmIndex),
+    /// The word is one of the forms of an explicitly listed lexeme
+    Lexeme(usize, ParadigmIndex),
+}
+
+/// Words registered at runtime on top of the compiled dictionary.
+///
+/// Lexeme ids depend on the registration order, so the ids of parses
+/// (see `MorphySerde`) are only valid for analyzers with the same lexicon.
+///
+/// Words are indexed with the char substitutes of the analyzer
+/// (see `MorphAnalyzer::char_substitutes`), so the same ones must be used to look them up.
+#[derive(Debug, Default, Clone)]
+pub struct UserLexicon {
+    /// Normalized form -> (form, entry)
+    index: HashMap<String, Vec<(String, UserEntry)>>,
+    lexemes: Vec<Vec<(String, OpencorporaTagReg)>>,
+}
+
+impl UserLexicon {
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+
+    /// Registers the word `lemma` inflected by the paradigm `para_id`.
+    pub fn add_with_paradigm<Id>(
+        &mut self,
+        dict: &Dictionary,
+        char_substitutes: &BTreeMap<String, String>,
+        lemma: &str,
+        para_id: Id,
+    ) -> Result<(), LexiconError>
+    where
+        Id: Into<ParadigmId>,
+    {
+        let para_id = para_id.into();
+        let lemma = lemma.to_lowercase();
+        if para_id.value() as usize >= dict.paradigms.len() {
+            return Err(LexiconError::UnknownParadigm(para_id));
+        }
+        let (prefix, _, suffix) = dict.paradigm_entry_info(dict.get_paradigm(para_id)[0]);
+        if lemma.len() < prefix.len() + suffix.len()
+            || !lemma.starts_with(prefix)
+            || !lemma.ends_with(suffix)
+        {
+            return Err(LexiconError::ParadigmMismatch { lemma, para_id });
+        }
+        let stem = &lemma[prefix.len()..lemma.len() - suffix.len()];
+        for (idx, (prefix, _, suffix)) in dict.iter_paradigm_info(para_id).enumerate() {
+            let form = format!("{}{}{}", prefix, stem, suffix);
+            let entry = UserEntry::Paradigm(para_id, idx.into());
+            self.insert(char_substitutes, form, entry);
+        }
+        Ok(())
+    }
+
+    /// Registers the word `lemma` inflected the same way as the dictionary word `example`.
+    ///
+    /// The paradigm of a lemma parse of `example` is preferred if it has several parses.
+    pub fn add_like(
+        &mut self,
+        dict: &Dictionary,
+        char_substitutes: &BTreeMap<String, String>,
+        lemma: &str,
+        example: &str,
+    ) -> Result<(), LexiconError> {
+        let example = example.to_lowercase();
+        let parses: Vec<HH> = dict
+            .words
+            .similar_items(&example, char_substitutes)
+            .into_iter()
+            .flat_map(|(_, parses)| parses)
+            .collect();
+        let HH(para_id, _) = parses
+            .iter()
+            .find(|&&HH(_, idx)| idx == 0)
+            .or_else(|| parses.first())
+            .cloned()
+            .ok_or_else(|| LexiconError::UnknownExample(example.clone()))?;
+        self.add_with_paradigm(dict, char_substitutes, lemma, para_id)
+    }
+
+    /// Registers a lexeme given as a list of `(form, tag)` pairs; the first form is the normal one.
+    ///
+    /// Returns the id of the lexeme.
+    pub fn add_lexeme<I, W, T>(
+        &mut self,
+        dict: &Dictionary,
+        char_substitutes: &BTreeMap<String, String>,
+        forms: I,
+    ) -> Result<usize, LexiconError>
+    where
+        I: IntoIterator<Item = (W, T)>,
+        W: AsRef<str>,
+        T: Into<String>,
+    {
+        let forms = forms
+            .into_iter()
+            .map(|(form, tag)| {
+                let tag = tag.into();
+                let known = GrammemeSet::new(&tag)
+                    .set
+                    .iter()
+                    .all(|grammeme| dict.grammemes.contains_key(grammeme));
+                if !known {
+                    return Err(LexiconError::UnknownTag(tag));
+                }
+                Ok((form.as_ref().to_lowercase(), OpencorporaTagReg::new(tag)))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if forms.is_empty() {
+            return Err(LexiconError::EmptyLexeme);
+        }
+        let id = self.lexemes.len();
+        for (idx, (form, _)) in forms.iter().enumerate() {
+            self.insert(
+                char_substitutes,
+                form.clone(),
+                UserEntry::Lexeme(id, idx.into()),
+            );
+        }
+        self.lexemes.push(forms);
+        Ok(id)
+    }
+
+    /// Returns the forms and entries matching the word.
+    pub fn lookup<'a>(
+        &'a self,
+        char_substitutes: &BTreeMap<String, String>,
+        word_lower: &str,
+    ) -> &'a [(String, UserEntry)] {
+        self.index
+            .get(&normalize(char_substitutes, word_lower))
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    /// Checks the explicitly listed lexeme `id` has the form `idx`.
+    pub fn has_form(&self, id: usize, idx: ParadigmIndex) -> bool {
+        match self.lexemes.get(id) {
+            Some(forms) => (idx.value() as usize) < forms.len(),
+            None => false,
+        }
+    }
+
+    /// Returns forms of the explicitly listed lexeme with their tags.
+    pub fn get_lexeme(&self, id: usize) -> &[(String, OpencorporaTagReg)] {
+        &self.lexemes[id]
+    }
+
+    pub fn get_tag(&self, id: usize, idx: ParadigmIndex) -> &OpencorporaTagReg {
+        &self.lexemes[id][idx.value() as usize].1
+    }
+
+    pub fn get_normal_form(&self, id: usize) -> &str {
+        &self.lexemes[id][0].0
+    }
+
+    fn insert(
+        &mut self,
+        char_substitutes: &BTreeMap<String, String>,
+        form: String,
+        entry: UserEntry,
+    ) {
+        let entries = self
+            .index
+            .entry(normalize(char_substitutes, &form))
+            .or_default();
+        let item = (form, entry);
+        if !entries.contains(&item) {
+            entries.push(item);
+        }
+    }
+}
+
+/// Replaces letters which may be written instead of others (e.g. е) with the letters
+/// they stand for (e.g. ё), so the user words are found whichever way they are spelled.
+fn normalize(char_substitutes: &BTreeMap<String, String>, word: &str) -> String {
+    char_substitutes
+        .iter()
+        .fold(word.to_owned(), |word, (from, to)| word.replace(from, to))
+}
+
+/// An error occurred while registering a user word.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LexiconError {
+    /// There is no paradigm with such an id in the dictionary
+    UnknownParadigm(ParadigmId),
+    /// The lemma doesn't have the prefix and the suffix of the normal form of the paradigm
+    ParadigmMismatch { lemma: String, para_id: ParadigmId },
+    /// The example word is absent in the dictionary
+    UnknownExample(String),
+    /// The tag contains grammemes which are absent in the dictionary
+    UnknownTag(String),
+    /// The lexeme has no forms
+    EmptyLexeme,
+}
+
+impl fmt::Display for LexiconError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LexiconError::UnknownParadigm(para_id) => {
+                write!(f, "unknown paradigm {:#x}", para_id.value())
+            }
+            LexiconError::ParadigmMismatch { lemma, para_id } => write!(
+                f,
+                "`{}` can't be the normal form of paradigm {:#x}",
+                lemma,
+                para_id.value()
+            ),
+            LexiconError::UnknownExample(word) => write!(f, "unknown example word `{}`", word),
+            LexiconError::UnknownTag(tag) => write!(f, "unknown grammemes in tag `{}`", tag),
+            LexiconError::EmptyLexeme => write!(f, "the lexeme has no forms"),
+        }
+    }
+}
+
+impl Error for LexiconError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        container::{abc::*, Lex, Parsed},
+        language::Language,
+        opencorpora::GrammemeSet,
+        MorphAnalyzer,
+    };
+
+    lazy_static::lazy_static! {
+        static ref RU: MorphAnalyzer = MorphAnalyzer::from_file(dict_ru::DICT_PATH);
+    }
+
+    #[test]
+    fn user_lexicon() {
+        let mut morph = RU.clone();
+        morph.add_word_like("маркетплейс", "магазин").unwrap();
+        let id = morph
+            .add_lexeme(vec![
+                ("стартаперша", "NOUN,anim,femn sing,nomn"),
+                ("стартаперши", "NOUN,anim,femn sing,gent"),
+                ("стартаперши", "NOUN,anim,femn plur,nomn"),
+            ])
+            .unwrap();
+        assert_eq!(id, 0);
+        assert_eq!(
+            morph.add_lexeme(vec![("слово", "NOUN,xxxx")]),
+            Err(LexiconError::UnknownTag("NOUN,xxxx".into()))
+        );
+
+        let parsed = morph.parse("маркетплейсами");
+        let lex = &parsed[0].lex;
+        assert_eq!(lex.get_normal_form(&morph), "маркетплейс");
+        let gent = lex.inflect(&morph, &GrammemeSet::new("sing,gent")).unwrap();
+        assert_eq!(gent.get_word(), "маркетплейса");
+
+        let parsed = morph.parse("стартаперши");
+        assert_eq!(parsed.len(), 2);
+        for p in &parsed {
+            assert_eq!(p.lex.get_normal_form(&morph), "стартаперша");
+            assert_eq!(p.lex.get_lexeme(&morph).len(), 3);
+            let id = p.lex.encoded();
+            assert_eq!(Lex::from_id(&morph, &id).unwrap(), p.lex);
+            // There is no such lexeme in the lexicon of another analyzer
+            assert_eq!(Lex::from_id(&RU, &id), Err(DecodeError::DoesntMatch));
+        }
+
+        // A form of the dictionary with the same tag is not repeated
+        let tag = "NOUN,inan,masc sing,nomn";
+        morph.add_lexeme(vec![("стол", tag)]).unwrap();
+        let parsed = morph.parse("стол");
+        let same = |p: &&Parsed| p.lex.get_tag(&morph).string == tag;
+        assert_eq!(parsed.iter().filter(same).count(), 1);
+        let mut tags: Vec<_> = parsed.iter().map(|p| p.lex.get_tag(&morph)).collect();
+        let mut fast_tags = morph.tag("стол");
+        tags.sort_by(|a, b| a.string.cmp(&b.string));
+        fast_tags.sort_by(|a, b| a.string.cmp(&b.string));
+        assert_eq!(tags, fast_tags);
+    }
+
+    #[test]
+    fn normalize_substitutes() {
+        let substitutes = Language::Ukrainian.char_substitutes();
+        for word in &["м’ята", "мʼята", "м‘ята", "м`ята"] {
+            assert_eq!(normalize(&substitutes, word), "м'ята");
+        }
+        assert_eq!(normalize(&substitutes, "гава"), "ґава");
+
+        let substitutes = Language::Russian.char_substitutes();
+        assert_eq!(normalize(&substitutes, "елка"), "ёлка");
+        assert_eq!(normalize(&substitutes, "ёлка"), "ёлка");
+    }
+
+    #[test]
+    fn lookup_substitutes() {
+        let substitutes = Language::Ukrainian.char_substitutes();
+        let mut lexicon = UserLexicon::default();
+        let tag = "NOUN,inan,femn sing,nomn";
+        lexicon
+            .add_lexeme(&RU.dict, &substitutes, vec![("м'ята", tag)])
+            .unwrap();
+        for word in &["м'ята", "м’ята", "мʼята"] {
+            let found = lexicon.lookup(&substitutes, word);
+            assert_eq!(
+                found,
+                [("м'ята".to_owned(), UserEntry::Lexeme(0, 0_usize.into()))]
+            );
+        }
+    }
+}
@@ -1,10 +1,18 @@
+pub mod corpus;
 pub mod dictionary;
+pub mod error;
 pub mod grammeme;
 pub mod kind;
+pub mod lexicon;
+pub mod numeral;
 pub mod paradigm;
+pub mod probability;
 pub mod tag;
 
 pub use self::dictionary::Dictionary;
+pub use self::error::LoadError;
 pub use self::grammeme::Grammeme;
 pub use self::grammeme::GrammemeSet;
+pub use self::lexicon::{LexiconError, UserLexicon};
+pub use self::numeral::Numeral;
 pub use self::tag::OpencorporaTagReg;
@@ -0,0 +1,129 @@
+use std::{error::Error, fmt, str::FromStr};
+
+/**
+    A number a word agrees with (see `Lex::get_plural`).
+
+    Only the form of agreement matters, so any integer (of any sign and size),
+    a decimal fraction and the words "полтора", "полторы", "оба", "обе" are accepted:
+
+    ```
+    use rsmorphy::opencorpora::Numeral;
+
+    assert_eq!(Numeral::from(-1), Numeral::One);
+    assert_eq!(Numeral::from(u64::max_value()), Numeral::Many);
+    assert_eq!(Numeral::from(22_i128), Numeral::Few);
+    assert_eq!(Numeral::from(1.5), Numeral::Fraction);
+    assert_eq!(Numeral::from(3.0), Numeral::Few);
+
+    assert_eq!("1,5".parse(), Ok(Numeral::Fraction));
+    assert_eq!("-11".parse(), Ok(Numeral::Many));
+    assert_eq!("100000000000000000000000000000000000000001".parse(), Ok(Numeral::One));
+    assert_eq!("полторы".parse(), Ok(Numeral::Few));
+    assert_eq!("обе".parse(), Ok(Numeral::Few));
+    assert!("1.2.3".parse::<Numeral>().is_err());
+    ```
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Numeral {
+    /// 1, 21, 101, ...: один рубль
+    One,
+    /// 2-4, 22-24, ..., полтора, оба: два рубля
+    Few,
+    /// 0, 5-20, 25-30, ...: пять рублей
+    Many,
+    /// A decimal fraction: 1,5 рубля
+    Fraction,
+}
+
+impl Numeral {
+    /// Returns the form of agreement with an integer by its last two digits.
+    fn from_last_digits(num: u8) -> Self {
+        match num {
+            num if (num % 10 == 1) && (num % 100 != 11) => Numeral::One,
+            num if (num % 10 >= 2) && (num % 10 <= 4) && (num % 100 < 10 || num % 100 >= 20) => {
+                Numeral::Few
+            }
+            _ => Numeral::Many,
+        }
+    }
+}
+
+macro_rules! numeral_from_unsigned {
+    ($($t:ty),*) => {$(
+        impl From<$t> for Numeral {
+            fn from(num: $t) -> Self {
+                Numeral::from_last_digits((num % 100) as u8)
+            }
+        }
+    )*};
+}
+
+macro_rules! numeral_from_signed {
+    ($($t:ty),*) => {$(
+        impl From<$t> for Numeral {
+            fn from(num: $t) -> Self {
+                Numeral::from_last_digits((num % 100).unsigned_abs() as u8)
+            }
+        }
+    )*};
+}
+
+numeral_from_unsigned!(u8, u16, u32, u64, u128, usize);
+numeral_from_signed!(i8, i16, i32, i64, i128, isize);
+
+impl From<f64> for Numeral {
+    fn from(num: f64) -> Self {
+        if num.is_finite() && num.fract() == 0.0 {
+            Numeral::from_last_digits((num % 100.0).abs() as u8)
+        } else {
+            Numeral::Fraction
+        }
+    }
+}
+
+impl From<f32> for Numeral {
+    fn from(num: f32) -> Self {
+        Numeral::from(f64::from(num))
+    }
+}
+
+impl FromStr for Numeral {
+    type Err = ParseNumeralError;
+
+    /// Parses an integer or a decimal fraction with "." or "," as the separator
+    /// (the fraction is a fraction even if it's zero: "1,0 литра").
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "полтора" | "полторы" | "оба" | "обе" => return Ok(Numeral::Few),
+            _ => {}
+        }
+        let digits = s.trim_start_matches(&['-', '+'][..]);
+        let (int, fract) = match digits.find(&['.', ','][..]) {
+            Some(pos) => (&digits[..pos], Some(&digits[pos + 1..])),
+            None => (digits, None),
+        };
+        let is_number = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+        if digits.len() + 1 < s.len() || !is_number(int) || !fract.map_or(true, is_number) {
+            return Err(ParseNumeralError);
+        }
+        if fract.is_some() {
+            return Ok(Numeral::Fraction);
+        }
+        let last_digits = &int[int.len().saturating_sub(2)..];
+        Ok(Numeral::from_last_digits(
+            last_digits.parse().map_err(|_| ParseNumeralError)?,
+        ))
+    }
+}
+
+/// The string is neither a number nor a numeral word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseNumeralError;
+
+impl fmt::Display for ParseNumeralError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid numeral")
+    }
+}
+
+impl Error for ParseNumeralError {}
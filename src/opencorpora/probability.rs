@@ -0,0 +1,170 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs::File,
+    io::{self, BufWriter},
+    path::Path,
+};
+
+use flate2::{write::GzEncoder, Compression};
+
+use crate::{
+    analyzer::MorphAnalyzer,
+    dawg::builder::IntDawgBuilder,
+    opencorpora::{corpus::TaggedWord, GrammemeSet},
+};
+
+/// Probabilities are stored as integers multiplied by this value.
+pub const MULTIPLIER: f64 = 1_000_000.0;
+
+/**
+    Estimates P(t|w) from a disambiguated corpus the same way as pymorphy2 does,
+    producing `p_t_given_w.intdawg` for `SingleTagProbabilityEstimator`.
+
+    Only ambiguous words (having several tags in the analyzer) are counted; tags of the corpus
+    are matched with the tags of the analyzer by their grammemes. P(t|w) of all tags
+    of a word is estimated with the add-one smoothing, and words whose tags are
+    equiprobable are skipped.
+*/
+#[derive(Debug, Default, Clone)]
+pub struct ProbabilityTrainer {
+    /// Word -> tag -> the number of occurrences
+    counts: HashMap<String, HashMap<String, u64>>,
+}
+
+impl ProbabilityTrainer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Counts a word of the corpus; returns `false` if it is skipped.
+    pub fn add_word(&mut self, morph: &MorphAnalyzer, word: &str, tag: &str) -> bool {
+        let word = word.to_lowercase();
+        let grammemes = GrammemeSet::new(tag);
+        let tags = tags(morph, &word);
+        if tags.len() < 2 {
+            return false;
+        }
+        let tag = match tags
+            .into_iter()
+            .find(|tag| GrammemeSet::new(tag) == grammemes)
+        {
+            Some(tag) => tag,
+            None => return false,
+        };
+        *self.counts.entry(word).or_default().entry(tag).or_default() += 1;
+        true
+    }
+
+    /// Counts all words of the sentences.
+    pub fn add_sentences<'s, I>(&mut self, morph: &MorphAnalyzer, sentences: I)
+    where
+        I: IntoIterator<Item = &'s Vec<TaggedWord>>,
+    {
+        for word in sentences.into_iter().flatten() {
+            self.add_word(morph, &word.word, &word.tag);
+        }
+    }
+
+    /// Returns P(t|w) of the words seen at least `min_word_freq` times, keyed by "word:tag".
+    pub fn probabilities(
+        &self,
+        morph: &MorphAnalyzer,
+        min_word_freq: u64,
+    ) -> BTreeMap<String, f64> {
+        let mut result = BTreeMap::new();
+        for (word, counts) in &self.counts {
+            let total: u64 = counts.values().sum();
+            if total < min_word_freq {
+                continue;
+            }
+            let tags = tags(morph, word);
+            let probs: Vec<f64> = tags
+                .iter()
+                .map(|tag| {
+                    let count = counts.get(tag).cloned().unwrap_or(0);
+                    (count + 1) as f64 / (total + tags.len() as u64) as f64
+                })
+                .collect();
+            if probs.windows(2).all(|pair| pair[0] == pair[1]) {
+                continue;
+            }
+            for (tag, prob) in tags.iter().zip(probs) {
+                result.insert(format!("{}:{}", word, tag), prob);
+            }
+        }
+        result
+    }
+
+    /// Builds `ConditionalProbDistDAWG` of pymorphy2.
+    pub fn build(&self, morph: &MorphAnalyzer, min_word_freq: u64) -> IntDawgBuilder {
+        let mut dawg = IntDawgBuilder::new();
+        for (key, prob) in self.probabilities(morph, min_word_freq) {
+            dawg.insert(&key, (prob * MULTIPLIER) as u32);
+        }
+        dawg
+    }
+
+    /// Saves the estimates as a gzipped DAWG which may replace `p_t_given_w.intdawg.gz`
+    /// of a dictionary.
+    pub fn save<P>(&self, morph: &MorphAnalyzer, min_word_freq: u64, p: P) -> io::Result<()>
+    where
+        P: AsRef<Path>,
+    {
+        let file = BufWriter::new(File::create(p)?);
+        let mut encoder = GzEncoder::new(file, Compression::default());
+        self.build(morph, min_word_freq).write(&mut encoder)?;
+        encoder.finish()?;
+        Ok(())
+    }
+}
+
+/// Distinct tags of the word in the analyzer.
+fn tags(morph: &MorphAnalyzer, word: &str) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for tag in morph.tag(word) {
+        if !tags.contains(&tag.string) {
+            tags.push(tag.string.clone());
+        }
+    }
+    tags
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        dawg::{CompletionDawg, HH},
+        opencorpora::{corpus::TaggedWord, kind::pos::PartOfSpeach},
+        MorphAnalyzer,
+    };
+
+    lazy_static::lazy_static! {
+        static ref RU: MorphAnalyzer = MorphAnalyzer::from_file(dict_ru::DICT_PATH);
+    }
+
+    #[test]
+    fn train_p_t_given_w() {
+        let verb = RU
+            .tag("стали")
+            .into_iter()
+            .find(|tag| tag.pos == Some(PartOfSpeach::Verb))
+            .unwrap()
+            .string
+            .clone();
+        let sentence = vec![TaggedWord::new("Стали", verb.as_str())];
+        let mut trainer = ProbabilityTrainer::new();
+        trainer.add_sentences(&RU, vec![&sentence; 3]);
+        assert!(!trainer.add_word(&RU, "стали", "LATN"));
+
+        let probs = trainer.probabilities(&RU, 1);
+        let sum: f64 = probs.values().sum();
+        assert!((sum - 1.0).abs() < 1e-9);
+        let key = format!("стали:{}", verb);
+        assert!(probs.values().all(|&p| p <= probs[&key]));
+
+        let path = std::env::temp_dir().join("rsmorphy-p_t_given_w.intdawg.gz");
+        trainer.save(&RU, 1, &path).unwrap();
+        let dawg = CompletionDawg::<HH>::from_file(&path);
+        assert_eq!(dawg.find(&key), Some((probs[&key] * 1_000_000.0) as u32));
+    }
+}
@@ -7,7 +7,7 @@ use maplit::hashset;
 
 use crate::{
     analyzer::MorphAnalyzer,
-    opencorpora::{grammeme::GrammemeSet, kind::*},
+    opencorpora::{grammeme::GrammemeSet, kind::*, numeral::Numeral},
 };
 
 //#[derive(Deserialize)]
@@ -103,11 +103,20 @@ impl OpencorporaTagReg {
         GrammemeSet { set: new_grammemes }
     }
 
-    pub fn numeral_agreement_grammemes(&self, num: usize) -> GrammemeSet {
+    /// Returns grammemes of the form agreeing with the number.
+    ///
+    /// In the nominative and accusative a noun agrees with a fraction in genitive singular
+    /// (1,5 рубля) and an adjective in genitive plural (1,5 новых); in other cases a noun
+    /// takes plural (полутора рублям).
+    pub fn numeral_agreement_grammemes<N>(&self, num: N) -> GrammemeSet
+    where
+        N: Into<Numeral>,
+    {
+        let num = num.into();
         let index = match num {
-            num if (num % 10 == 1) && (num % 100 != 11) => 0,
-            num if (num % 10 >= 2) && (num % 10 <= 4) && (num % 100 < 10 || num % 100 >= 20) => 1,
-            _ => 2,
+            Numeral::One => 0,
+            Numeral::Few => 1,
+            Numeral::Many | Numeral::Fraction => 2,
         };
 
         let x = match self.pos {
@@ -122,6 +131,12 @@ impl OpencorporaTagReg {
                         }
                     }
 
+                    Some(PartOfSpeach::Noun) if num == Numeral::Fraction => {
+                        Some((Number::Sing, Some(Case::Gent)))
+                    }
+
+                    _ if num == Numeral::Fraction => Some((Number::Plur, Some(Case::Gent))),
+
                     _ if index == 0 => match self.case {
                         Some(Case::Nomn) => Some((Number::Sing, Some(Case::Nomn))),
                         _ => Some((Number::Sing, Some(Case::Accs))),
@@ -153,6 +168,80 @@ impl OpencorporaTagReg {
             },
         }
     }
+
+    /// Returns grammemes of an adjective or a participle agreeing with the number
+    /// in a phrase with the `head` noun (taken before its agreement):
+    /// "21 активный пользователь", "2 новых сообщения", "2 новые книги".
+    ///
+    /// The set is empty for other parts of speech.
+    pub fn modifier_agreement_grammemes<N>(&self, num: N, head: &OpencorporaTagReg) -> GrammemeSet
+    where
+        N: Into<Numeral>,
+    {
+        let (number, case) = match head.case.map(Case::main) {
+            Some(case) if case != Case::Nomn && case != Case::Accs => match num.into() {
+                Numeral::One => (Number::Sing, case),
+                _ => (Number::Plur, case),
+            },
+            case => match num.into() {
+                Numeral::One => (Number::Sing, case.unwrap_or(Case::Nomn)),
+                Numeral::Few if head.gender == Some(Gender::Femn) => (Number::Plur, Case::Nomn),
+                _ => (Number::Plur, Case::Gent),
+            },
+        };
+        self.agreeing_grammemes(number, case, head)
+    }
+
+    /// Returns grammemes of an adjective or a participle agreeing with the `head` noun
+    /// in number, case and gender: "на красной площади", "новому учебному году".
+    ///
+    /// The set is empty for other parts of speech or if the head has no number or case.
+    pub fn head_agreement_grammemes(&self, head: &OpencorporaTagReg) -> GrammemeSet {
+        match (head.number, head.case) {
+            (Some(number), Some(case)) => self.agreeing_grammemes(number, case.main(), head),
+            _ => GrammemeSet::default(),
+        }
+    }
+
+    /// Checks if an adjective or a participle agrees with the `head` noun in number, case
+    /// and gender (in singular), i.e. depends on it rather than on another noun.
+    pub fn agrees_with(&self, head: &OpencorporaTagReg) -> bool {
+        let same_case = match (self.case, head.case) {
+            (Some(case), Some(head_case)) => case.main() == head_case.main(),
+            _ => false,
+        };
+        let same_gender = self.number == Some(Number::Plur)
+            || self.gender.is_none()
+            || head.gender.is_none()
+            || self.gender == head.gender;
+        let is_modifier = matches!(
+            self.pos,
+            Some(PartOfSpeach::Adjf) | Some(PartOfSpeach::Prtf)
+        );
+        is_modifier && same_case && self.number == head.number && same_gender
+    }
+
+    /// Adds the gender of the head in singular and its animacy in accusative.
+    fn agreeing_grammemes(
+        &self,
+        number: Number,
+        case: Case,
+        head: &OpencorporaTagReg,
+    ) -> GrammemeSet {
+        match self.pos {
+            Some(PartOfSpeach::Adjf) | Some(PartOfSpeach::Prtf) => (),
+            _ => return GrammemeSet::default(),
+        };
+
+        let mut set = hashset! { number.to_grammeme(), case.to_grammeme() };
+        if number == Number::Sing {
+            set.extend(head.gender.map(Gender::to_grammeme));
+        }
+        if case == Case::Accs {
+            set.extend(head.animacy.map(Animacy::to_grammeme));
+        }
+        GrammemeSet { set }
+    }
 }
 
 //#[cfg(test)]
@@ -65,3 +65,43 @@ pub fn is_punctuation(token: &str) -> bool {
 pub fn is_roman_number(token: &str) -> bool {
     roman::from(token).is_some()
 }
+
+/**
+    Make the capitalization of the word the same as of the example:
+    lower, upper, title case (hyphenated parts too) or left as is otherwise:
+
+    ```
+    use rsmorphy::shapes::restore_capitalization;
+
+    assert_eq!(restore_capitalization("бутявкой", "БУТЯВКА"), "БУТЯВКОЙ");
+    assert_eq!(restore_capitalization("бутявкой", "Бутявка"), "Бутявкой");
+    assert_eq!(restore_capitalization("римскому-корсакову", "Римский-Корсаков"), "Римскому-Корсакову");
+    assert_eq!(restore_capitalization("Бутявкой", "бутявка"), "бутявкой");
+    assert_eq!(restore_capitalization("бутявкой", "бУтявка"), "бутявкой");
+    ```
+*/
+pub fn restore_capitalization(word: &str, example: &str) -> String {
+    let is_title =
+        |s: &str| s.starts_with(char::is_uppercase) && !s.chars().skip(1).any(char::is_uppercase);
+    if example.chars().any(char::is_lowercase) && !example.chars().any(char::is_uppercase) {
+        word.to_lowercase()
+    } else if example.chars().any(char::is_uppercase) && !example.chars().any(char::is_lowercase) {
+        word.to_uppercase()
+    } else if example.split('-').all(is_title) {
+        word.split('-')
+            .map(|part| {
+                let mut chars = part.chars();
+                match chars.next() {
+                    Some(first) => first
+                        .to_uppercase()
+                        .chain(chars.flat_map(char::to_lowercase))
+                        .collect(),
+                    None => String::new(),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("-")
+    } else {
+        word.to_owned()
+    }
+}
@@ -0,0 +1,319 @@
+use crate::abbreviations::Abbreviations;
+
+/// A kind of a token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+    /// A word, possibly hyphenated or with an apostrophe: "слово", "по-русски", "м'ясо"
+    Word,
+    /// An integer or a decimal number, possibly with a suffix: "42", "3,14", "1990-х";
+    /// numbers of a list ("1,2,3") are separate tokens
+    Number,
+    /// Single letters with dots: "т.е.", "А.С.", "А." (an initial followed by a capitalized word);
+    /// with `tokenize_with_abbreviations` also known abbreviations: "ул.", "руб."
+    Abbreviation,
+    /// Punctuation marks: ",", "...", "?!"
+    Punctuation,
+    /// Any other character which is not a space: "+", "№"
+    Other,
+}
+
+/// A token of a text with its offsets in bytes and in chars.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Token<'t> {
+    pub text: &'t str,
+    pub kind: TokenKind,
+    /// Byte offset of the first char
+    pub start: usize,
+    /// Byte offset after the last char
+    pub end: usize,
+    /// Char offset of the first char
+    pub char_start: usize,
+    /// Char offset after the last char
+    pub char_end: usize,
+}
+
+regex!(
+    TOKEN_RE,
+    r"(?x)
+        (?P<abbr> (?: \p{L} \. ){2,} | \p{Lu} \. )
+        | (?P<number> \d+ (?: [.,] \d+ )? (?: - [\p{L}\p{M}]+ )? )
+        | (?P<word> \p{L} [\p{L}\p{M}\d]* (?: [-'’ʼ] [\p{L}\p{M}\d]+ )* )
+        | (?P<punct> \.{2,} | [!?]+ | \p{P} )
+        | (?P<other> \S )
+    "
+);
+
+/**
+    Splits the text into tokens the analyzer units expect; spaces are skipped.
+
+    Hyphenated words ("по-русски", "кто-то"), numbers with suffixes ("1990-х"),
+    abbreviations ("т.е."), initials ("А. С. Пушкин") and decimal numbers ("3,14")
+    are kept together:
+
+    ```
+    use rsmorphy::tokenize::{tokenize, TokenKind};
+
+    let tokens: Vec<_> = tokenize("Кто-то, т.е. А.С. Пушкин, пишет по-русски в 1990-х: 3,14...")
+        .map(|t| (t.text, t.kind))
+        .collect();
+    assert_eq!(tokens, vec![
+        ("Кто-то", TokenKind::Word),
+        (",", TokenKind::Punctuation),
+        ("т.е.", TokenKind::Abbreviation),
+        ("А.С.", TokenKind::Abbreviation),
+        ("Пушкин", TokenKind::Word),
+        (",", TokenKind::Punctuation),
+        ("пишет", TokenKind::Word),
+        ("по-русски", TokenKind::Word),
+        ("в", TokenKind::Word),
+        ("1990-х", TokenKind::Number),
+        (":", TokenKind::Punctuation),
+        ("3,14", TokenKind::Number),
+        ("...", TokenKind::Punctuation),
+    ]);
+
+    // A capital letter ending a sentence is not an initial, and "1,2,3" is not a number
+    let tokens: Vec<_> = tokenize("Вариант Б. Пункты 1,2,3.").map(|t| t.text).collect();
+    assert_eq!(tokens, ["Вариант", "Б.", "Пункты", "1", ",", "2", ",", "3", "."]);
+    let tokens: Vec<_> = tokenize("Верный ответ Б.").map(|t| t.text).collect();
+    assert_eq!(tokens, ["Верный", "ответ", "Б", "."]);
+
+    let token = tokenize("Привет, мир").nth(2).unwrap();
+    assert_eq!((token.start, token.end), (14, 20));
+    assert_eq!((token.char_start, token.char_end), (8, 11));
+    assert_eq!(token.text, &"Привет, мир"[token.start..token.end]);
+    ```
+*/
+pub fn tokenize(text: &str) -> Tokens<'_, 'static> {
+    Tokens {
+        text,
+        pos: 0,
+        char_pos: 0,
+        abbreviations: None,
+    }
+}
+
+/**
+    Splits the text into tokens like `tokenize`, also keeping a word together
+    with the following dot if they make a known abbreviation:
+
+    ```
+    use rsmorphy::abbreviations::Abbreviations;
+    use rsmorphy::tokenize::{tokenize_with_abbreviations, TokenKind};
+
+    let abbreviations = Abbreviations::parse("ул.\tулица\tNOUN,inan,femn").unwrap();
+    let tokens: Vec<_> = tokenize_with_abbreviations("Ул. Ленина, улица.", &abbreviations)
+        .map(|t| (t.text, t.kind))
+        .collect();
+    assert_eq!(tokens, vec![
+        ("Ул.", TokenKind::Abbreviation),
+        ("Ленина", TokenKind::Word),
+        (",", TokenKind::Punctuation),
+        ("улица", TokenKind::Word),
+        (".", TokenKind::Punctuation),
+    ]);
+    ```
+*/
+pub fn tokenize_with_abbreviations<'t, 'a>(
+    text: &'t str,
+    abbreviations: &'a Abbreviations,
+) -> Tokens<'t, 'a> {
+    Tokens {
+        abbreviations: Some(abbreviations),
+        ..tokenize(text)
+    }
+}
+
+/// An iterator over the tokens of a text; see `tokenize`.
+#[derive(Debug)]
+pub struct Tokens<'t, 'a> {
+    text: &'t str,
+    /// Byte offset of the end of the last token
+    pos: usize,
+    /// Char offset of the end of the last token
+    char_pos: usize,
+    abbreviations: Option<&'a Abbreviations>,
+}
+
+impl<'t, 'a> Tokens<'t, 'a> {
+    /// Checks the word at `start..end` with the following dot is a known abbreviation;
+    /// a word followed by an ellipsis is not.
+    fn is_abbreviation(&self, start: usize, end: usize) -> bool {
+        let rest = &self.text[end..];
+        match self.abbreviations {
+            Some(abbreviations) if rest.starts_with('.') && !rest.starts_with("..") => {
+                let abbr = self.text[start..=end].to_lowercase();
+                !abbreviations.lookup_ids(&abbr).is_empty()
+            }
+            _ => false,
+        }
+    }
+
+    /// Checks the single letter with a dot at `start..end` is not an initial,
+    /// i.e. it isn't followed by another initial or a capitalized word.
+    fn is_not_initial(&self, start: usize, end: usize) -> bool {
+        self.text[start..end].chars().count() == 2
+            && !self.text[end..]
+                .trim_start()
+                .starts_with(char::is_uppercase)
+    }
+
+    /// Checks the number at `start..end` is a part of a list ("1,2,3")
+    /// rather than a decimal one.
+    fn is_list_item(&self, start: usize, end: usize) -> bool {
+        let is_separator = |ch: Option<char>| matches!(ch, Some('.') | Some(','));
+        let is_digit = |ch: Option<char>| match ch {
+            Some(ch) => ch.is_numeric(),
+            None => false,
+        };
+        let mut after = self.text[end..].chars();
+        let mut before = self.text[..start].chars().rev();
+        (is_separator(after.next()) && is_digit(after.next()))
+            || (is_separator(before.next()) && is_digit(before.next()))
+    }
+}
+
+impl<'t, 'a> Iterator for Tokens<'t, 'a> {
+    type Item = Token<'t>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cap = TOKEN_RE.captures(&self.text[self.pos..])?;
+        let (m, kind) = if let Some(m) = cap.name("abbr") {
+            (m, TokenKind::Abbreviation)
+        } else if let Some(m) = cap.name("number") {
+            (m, TokenKind::Number)
+        } else if let Some(m) = cap.name("word") {
+            (m, TokenKind::Word)
+        } else if let Some(m) = cap.name("punct") {
+            (m, TokenKind::Punctuation)
+        } else {
+            (cap.name("other")?, TokenKind::Other)
+        };
+        let (start, mut end) = (self.pos + m.start(), self.pos + m.end());
+        // The rest of a shortened token is matched again by the next call
+        let kind = match kind {
+            TokenKind::Word if self.is_abbreviation(start, end) => {
+                end += 1;
+                TokenKind::Abbreviation
+            }
+            TokenKind::Abbreviation if self.is_not_initial(start, end) => {
+                end -= 1;
+                TokenKind::Word
+            }
+            TokenKind::Number if self.is_list_item(start, end) => {
+                if let Some(separator) = self.text[start..end].find(&['.', ','][..]) {
+                    end = start + separator;
+                }
+                TokenKind::Number
+            }
+            kind => kind,
+        };
+        let text = &self.text[start..end];
+        let char_start = self.char_pos + self.text[self.pos..start].chars().count();
+        let char_end = char_start + text.chars().count();
+        self.pos = end;
+        self.char_pos = char_end;
+        Some(Token {
+            text,
+            kind,
+            start,
+            end,
+            char_start,
+            char_end,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MorphAnalyzer;
+
+    lazy_static::lazy_static! {
+        static ref RU: MorphAnalyzer = MorphAnalyzer::from_file(dict_ru::DICT_PATH);
+    }
+
+    #[test]
+    fn spans() {
+        let text = "Кто-то, т.е. А.С., пишет по-русски в 1990-х: 3,14...";
+        let tokens: Vec<_> = tokenize(text)
+            .map(|t| (t.text, t.start, t.end, t.char_start, t.char_end))
+            .collect();
+        assert_eq!(
+            tokens,
+            vec![
+                ("Кто-то", 0, 11, 0, 6),
+                (",", 11, 12, 6, 7),
+                ("т.е.", 13, 19, 8, 12),
+                ("А.С.", 20, 26, 13, 17),
+                (",", 26, 27, 17, 18),
+                ("пишет", 28, 38, 19, 24),
+                ("по-русски", 39, 56, 25, 34),
+                ("в", 57, 59, 35, 36),
+                ("1990-х", 60, 67, 37, 43),
+                (":", 67, 68, 43, 44),
+                ("3,14", 69, 73, 45, 49),
+                ("...", 73, 76, 49, 52),
+            ]
+        );
+        for (token, start, end, _, _) in tokens {
+            assert_eq!(&text[start..end], token);
+        }
+    }
+
+    #[test]
+    fn initials() {
+        let texts = |text| tokenize(text).map(|t| (t.text, t.kind)).collect::<Vec<_>>();
+        assert_eq!(
+            texts("А. С. Пушкин"),
+            [
+                ("А.", TokenKind::Abbreviation),
+                ("С.", TokenKind::Abbreviation),
+                ("Пушкин", TokenKind::Word),
+            ]
+        );
+        assert_eq!(
+            texts("вариант Б. Далее"),
+            [
+                ("вариант", TokenKind::Word),
+                ("Б.", TokenKind::Abbreviation),
+                ("Далее", TokenKind::Word),
+            ]
+        );
+        assert_eq!(
+            texts("вариант Б."),
+            [
+                ("вариант", TokenKind::Word),
+                ("Б", TokenKind::Word),
+                (".", TokenKind::Punctuation),
+            ]
+        );
+        assert_eq!(
+            texts("вариант Б... и"),
+            [
+                ("вариант", TokenKind::Word),
+                ("Б", TokenKind::Word),
+                ("...", TokenKind::Punctuation),
+                ("и", TokenKind::Word),
+            ]
+        );
+    }
+
+    #[test]
+    fn number_lists() {
+        let texts = |text| tokenize(text).map(|t| t.text).collect::<Vec<_>>();
+        assert_eq!(texts("1,2,3"), ["1", ",", "2", ",", "3"]);
+        assert_eq!(
+            texts("пункты 1.2.3."),
+            ["пункты", "1", ".", "2", ".", "3", "."]
+        );
+        assert_eq!(texts("3,14 и 2,5"), ["3,14", "и", "2,5"]);
+        assert_eq!(texts("1, 2,5, 3"), ["1", ",", "2,5", ",", "3"]);
+    }
+
+    #[test]
+    fn parse_tokens() {
+        let tags: Vec<_> = RU.tag("3,14").into_iter().map(|tag| &tag.string).collect();
+        assert_eq!(tags, vec!["NUMB,real"]);
+    }
+}